@@ -0,0 +1,261 @@
+//! Compares the flat storage of `util::grid::Grid` with the nested rows it replaced, on the
+//! solutions of 2025 day 04 and day 07. Run with `cargo bench --bench grid`.
+//!
+//! Both layouts run the solutions of `src/bin` as they are: they are included twice, once as is and
+//! once in a module where `advent_of_code::util::grid::Grid` resolves to [`NestedGrid`].
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use advent_of_code::template::bench::{BenchOptions, BenchStats};
+use advent_of_code::util::grid::Neighborhood;
+
+// NOTE: the tests of the solutions are not compiled into a bench, which leaves their imports unused.
+#[allow(unused_imports)]
+mod flat {
+    pub mod day_04 {
+        include!("../src/bin/2025-04.rs");
+    }
+
+    pub mod day_07 {
+        include!("../src/bin/2025-07.rs");
+    }
+}
+
+#[allow(unused_imports)]
+mod nested {
+    /// Stands in for the crate, with the nested grid in place of the flat one.
+    mod advent_of_code {
+        pub use ::advent_of_code::solution;
+
+        pub mod util {
+            pub mod grid {
+                pub use crate::NestedGrid as Grid;
+            }
+        }
+    }
+
+    pub mod day_04 {
+        use super::advent_of_code;
+
+        include!("../src/bin/2025-04.rs");
+    }
+
+    pub mod day_07 {
+        use super::advent_of_code;
+
+        include!("../src/bin/2025-07.rs");
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// The previous layout of `Grid`: one allocation per row. Neighbors are computed exactly like the
+/// flat grid does, so that only the storage differs.
+#[derive(Clone)]
+pub struct NestedGrid {
+    pub width: usize,
+    pub height: usize,
+    elems: Vec<Vec<u8>>,
+}
+
+pub struct NestedElementIterator<'a> {
+    grid: &'a NestedGrid,
+    x: usize,
+    y: usize,
+    element: u8,
+}
+
+impl Iterator for NestedElementIterator<'_> {
+    type Item = (usize, usize);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        for j in self.y..self.grid.height {
+            for i in self.x..self.grid.width {
+                if self.grid.elems[j][i] == self.element {
+                    self.x = i + 1;
+                    self.y = j;
+
+                    return Some((i, j));
+                }
+            }
+            self.x = 0;
+        }
+
+        None
+    }
+}
+
+pub struct NestedAdjacentElementIterator<'a> {
+    grid: &'a NestedGrid,
+    x: usize,
+    y: usize,
+    element: u8,
+    offsets: std::slice::Iter<'static, (isize, isize)>,
+}
+
+impl Iterator for NestedAdjacentElementIterator<'_> {
+    type Item = (usize, usize);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        for (dx, dy) in &mut self.offsets {
+            if let Some(x) = self.x.checked_add_signed(*dx)
+                && let Some(y) = self.y.checked_add_signed(*dy)
+                && x < self.grid.width
+                && y < self.grid.height
+                && self.grid.elems[y][x] == self.element
+            {
+                return Some((x, y));
+            }
+        }
+
+        None
+    }
+}
+
+impl NestedGrid {
+    pub fn parse_input(input: &str) -> Self {
+        let elems: Vec<Vec<u8>> = input.lines().map(|line| line.bytes().collect()).collect();
+        Self {
+            width: elems.first().map_or(0, Vec::len),
+            height: elems.len(),
+            elems,
+        }
+    }
+
+    pub fn find(&self, element: u8) -> NestedElementIterator<'_> {
+        NestedElementIterator {
+            grid: self,
+            x: 0,
+            y: 0,
+            element,
+        }
+    }
+
+    pub fn adjacent_element(
+        &self,
+        x: usize,
+        y: usize,
+        element: u8,
+    ) -> NestedAdjacentElementIterator<'_> {
+        NestedAdjacentElementIterator {
+            grid: self,
+            x,
+            y,
+            element,
+            offsets: Neighborhood::Moore.offsets().iter(),
+        }
+    }
+
+    pub fn get(&self, (x, y): (usize, usize)) -> u8 {
+        self.elems[y][x]
+    }
+
+    pub fn set(&mut self, (x, y): (usize, usize), value: u8) {
+        self.elems[y][x] = value;
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Deterministic pseudo-random numbers, so that both layouts see the same input.
+fn random(seed: &mut u64) -> u64 {
+    *seed ^= *seed << 13;
+    *seed ^= *seed >> 7;
+    *seed ^= *seed << 17;
+    *seed
+}
+
+/// A map of paper rolls of the size of a day 04 input.
+fn day_04_input() -> String {
+    let mut seed = 4;
+    (0..140)
+        .map(|_| {
+            (0..140)
+                .map(|_| if random(&mut seed) % 10 < 7 { '@' } else { '.' })
+                .collect::<String>()
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// A tachyon manifold of the size of a day 07 input.
+fn day_07_input() -> String {
+    let mut seed = 7;
+    let width = 141;
+    (0..142)
+        .map(|y| {
+            (0..width)
+                .map(|x| match y {
+                    0 if x == width / 2 => 'S',
+                    _ if y % 2 == 0
+                        && x > 0
+                        && x < width - 1
+                        && random(&mut seed).is_multiple_of(3) =>
+                    {
+                        '^'
+                    }
+                    _ => '.',
+                })
+                .collect::<String>()
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Sample `func` like `cargo time` samples a part.
+fn bench<T>(func: impl Fn() -> T) -> BenchStats {
+    let options = BenchOptions::default();
+
+    let timer = Instant::now();
+    black_box(func());
+    let base_time = timer.elapsed();
+
+    for _ in 0..options.warmup_count(&base_time) {
+        black_box(func());
+    }
+
+    let samples: Vec<Duration> = (0..options.sample_count(&base_time))
+        .map(|_| {
+            let timer = Instant::now();
+            black_box(func());
+            timer.elapsed()
+        })
+        .collect();
+
+    // NOTE: `sample_count` is at least 1, so there is always a sample.
+    BenchStats::from_samples(&samples).unwrap()
+}
+
+fn report(name: &str, nested: &BenchStats, flat: &BenchStats) {
+    println!(
+        "{name}: nested {:.1?}, flat {:.1?} ({:.2}x)",
+        nested.median,
+        flat.median,
+        nested.median.as_secs_f64() / flat.median.as_secs_f64()
+    );
+}
+
+/// Bench a part on both layouts, after checking that they agree on its answer.
+macro_rules! compare {
+    ($name:expr, $day:ident, $part:ident, $input:expr) => {{
+        let (nested, flat) = (nested::$day::parse($input), flat::$day::parse($input));
+        assert_eq!(nested::$day::$part(&nested), flat::$day::$part(&flat));
+        report(
+            $name,
+            &bench(|| nested::$day::$part(black_box(&nested))),
+            &bench(|| flat::$day::$part(black_box(&flat))),
+        );
+    }};
+}
+
+fn main() {
+    let input = day_04_input();
+    compare!("Day 04, part 1", day_04, part_one, &input);
+    compare!("Day 04, part 2", day_04, part_two, &input);
+
+    let input = day_07_input();
+    compare!("Day 07, part 1", day_07, part_one, &input);
+    compare!("Day 07, part 2", day_07, part_two, &input);
+}
@@ -0,0 +1,51 @@
+//! Generates the list of solution modules that the `all_days` binary compiles in-process.
+use std::{env, fs, path::Path};
+
+fn main() {
+    println!("cargo::rerun-if-changed=src/bin");
+    println!("cargo::rerun-if-changed=build.rs");
+
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
+    let out_dir = env::var("OUT_DIR").unwrap();
+
+    // solution binaries are named after their year and day, e.g. `2025-01.rs`.
+    let mut days: Vec<(String, String)> = fs::read_dir(Path::new(&manifest_dir).join("src/bin"))
+        .unwrap()
+        .filter_map(|entry| {
+            let name = entry.ok()?.file_name().into_string().ok()?;
+            let (year, day) = name.strip_suffix(".rs")?.split_once('-')?;
+            year.parse::<u16>().ok()?;
+            day.parse::<u8>().ok()?;
+            Some((year.to_string(), day.to_string()))
+        })
+        .collect();
+    days.sort_unstable();
+
+    // every solution defines its own global allocator when profiling heap allocations.
+    if env::var_os("CARGO_FEATURE_DHAT_HEAP").is_some() {
+        days.clear();
+    }
+
+    let mut out = String::new();
+
+    for (year, day) in &days {
+        out.push_str(&format!(
+            "#[path = {:?}]\nmod day_{year}_{day};\n\n",
+            Path::new(&manifest_dir)
+                .join("src/bin")
+                .join(format!("{year}-{day}.rs"))
+        ));
+    }
+
+    out.push_str("pub fn registry() -> advent_of_code::template::registry::Registry {\n");
+    out.push_str("    advent_of_code::template::registry::Registry::new(vec![\n");
+    for (year, day) in &days {
+        let module = format!("day_{year}_{day}");
+        out.push_str(&format!(
+            "        advent_of_code::template::registry::Solution {{ year: {module}::YEAR, day: {module}::DAY, run: {module}::run_parts }},\n"
+        ));
+    }
+    out.push_str("    ])\n}\n");
+
+    fs::write(Path::new(&out_dir).join("days.rs"), out).unwrap();
+}
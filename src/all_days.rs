@@ -0,0 +1,45 @@
+//! Runs any subset of days in a single process, without spawning `cargo` for each of them.
+//!
+//! Usage: `all_days [--year <year>] [--time] [bench options] [days...]`. All registered days of
+//! the year are run when no day is passed. The year defaults to `AOC_YEAR`.
+use std::{collections::HashSet, process};
+
+use advent_of_code::template::{Day, Year, runner::Options};
+
+include!(concat!(env!("OUT_DIR"), "/days.rs"));
+
+fn parse() -> Result<(Year, Options, HashSet<Day>), Box<dyn std::error::Error>> {
+    let mut args = pico_args::Arguments::from_env();
+    let year = Year::from_arguments(&mut args)?;
+    let options = Options::from_arguments(&mut args)?;
+
+    let days = args
+        .finish()
+        .into_iter()
+        .map(|arg| -> Result<Day, Box<dyn std::error::Error>> {
+            Ok(year.check_day(arg.to_string_lossy().parse()?)?)
+        })
+        .collect::<Result<_, _>>()?;
+
+    Ok((year, options, days))
+}
+
+fn main() {
+    let registry = registry();
+
+    let (year, options, days_to_run) = match parse() {
+        Ok((year, options, days)) if days.is_empty() => {
+            (year, options, registry.days(year).collect())
+        }
+        Ok(parsed) => parsed,
+        Err(e) => {
+            eprintln!("Error: {e}");
+            process::exit(1);
+        }
+    };
+
+    let records = registry.run_days(year, &days_to_run, &options);
+    if records.iter().any(|r| r.status.is_failure()) {
+        process::exit(1);
+    }
+}
@@ -1,4 +1,4 @@
-advent_of_code::solution!(1);
+advent_of_code::solution!(2025, 1);
 
 pub fn part_one(input: &str) -> Option<u64> {
     let amounts = extract_amounts(input);
@@ -62,7 +62,7 @@ mod tests {
 
     #[test]
     fn test_part_one_example_1() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&advent_of_code::template::read_year_file("examples", YEAR, DAY));
         assert_eq!(result, Some(3));
     }
 
@@ -86,19 +86,19 @@ R100"#,
 
     #[test]
     fn test_part_one_solution() {
-        let result = part_one(&advent_of_code::template::read_file("inputs", DAY));
+        let result = part_one(&advent_of_code::template::read_year_file("inputs", YEAR, DAY));
         assert_eq!(result, Some(1141));
     }
 
     #[test]
     fn test_part_two_example_1() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&advent_of_code::template::read_year_file("examples", YEAR, DAY));
         assert_eq!(result, Some(6));
     }
 
     #[test]
     fn test_part_two_solution() {
-        let result = part_two(&advent_of_code::template::read_file("inputs", DAY));
+        let result = part_two(&advent_of_code::template::read_year_file("inputs", YEAR, DAY));
         assert_eq!(result, Some(6634));
     }
 }
@@ -1,4 +1,4 @@
-advent_of_code::solution!(2);
+advent_of_code::solution!(2025, 2);
 
 pub fn part_one(input: &str) -> Option<u64> {
     part_gen(input, is_invalid)
@@ -104,13 +104,13 @@ mod tests {
 
     #[test]
     fn test_part_one_example_1() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&advent_of_code::template::read_year_file("examples", YEAR, DAY));
         assert_eq!(result, Some(1227775554));
     }
 
     #[test]
     fn test_part_two_example_1() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&advent_of_code::template::read_year_file("examples", YEAR, DAY));
         assert_eq!(result, Some(4174379265));
     }
 
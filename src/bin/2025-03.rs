@@ -1,4 +1,4 @@
-advent_of_code::solution!(3);
+advent_of_code::solution!(2025, 3);
 
 fn part_gen(input: &str, joltage_computer: fn(&[u32]) -> u64) -> Option<u64> {
     Some(
@@ -101,7 +101,7 @@ mod tests {
 
     #[test]
     fn test_part_one_example_1() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&advent_of_code::template::read_year_file("examples", YEAR, DAY));
         assert_eq!(result, Some(357));
     }
 
@@ -155,7 +155,7 @@ mod tests {
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&advent_of_code::template::read_year_file("examples", YEAR, DAY));
         assert_eq!(result, Some(3121910778619));
     }
 
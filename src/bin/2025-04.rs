@@ -1,10 +1,12 @@
 use advent_of_code::util::grid::Grid;
 
-advent_of_code::solution!(4);
+advent_of_code::solution!(2025, 4, parse = parse);
 
-pub fn part_one(input: &str) -> Option<u64> {
-    let grid = Grid::parse_input(input);
+pub fn parse(input: &str) -> Grid {
+    Grid::parse_input(input)
+}
 
+pub fn part_one(grid: &Grid) -> Option<u64> {
     Some(
         grid.find(b'@')
             .map(|(x, y)| grid.adjacent_element(x, y, b'@').count())
@@ -13,8 +15,8 @@ pub fn part_one(input: &str) -> Option<u64> {
     )
 }
 
-pub fn part_two(input: &str) -> Option<u64> {
-    let mut grid = Grid::parse_input(input);
+pub fn part_two(grid: &Grid) -> Option<u64> {
+    let mut grid = grid.clone();
 
     let mut removed_total = 0;
     loop {
@@ -44,13 +46,15 @@ mod tests {
 
     #[test]
     fn test_part_one_example_1() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let input = advent_of_code::template::read_year_file("examples", YEAR, DAY);
+        let result = part_one(&parse(&input));
         assert_eq!(result, Some(13));
     }
 
     #[test]
     fn test_part_two_example_1() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let input = advent_of_code::template::read_year_file("examples", YEAR, DAY);
+        let result = part_two(&parse(&input));
         assert_eq!(result, Some(43));
     }
 }
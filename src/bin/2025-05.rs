@@ -1,16 +1,31 @@
 use itertools::enumerate;
 
-advent_of_code::solution!(5);
+advent_of_code::solution!(2025, 5, parse = parse);
 
-pub fn part_one(input: &str) -> Option<u64> {
+pub struct Inventory {
+    ranges: Vec<(u64, u64)>,
+    ids: Vec<u64>,
+}
+
+pub fn parse(input: &str) -> Inventory {
     let (ranges, end_of_ranges) = parse_ranges(input);
 
-    Some(
-        input
+    Inventory {
+        ranges,
+        ids: input
             .lines()
             .skip(end_of_ranges + 1)
             .map(|id_str| id_str.parse::<u64>().unwrap())
-            .filter(|id| is_fresh(*id, &ranges))
+            .collect(),
+    }
+}
+
+pub fn part_one(inventory: &Inventory) -> Option<u64> {
+    Some(
+        inventory
+            .ids
+            .iter()
+            .filter(|id| is_fresh(**id, &inventory.ranges))
             .count() as u64,
     )
 }
@@ -60,12 +75,11 @@ fn parse_range(line: &str) -> (u64, u64) {
     )
 }
 
-pub fn part_two(input: &str) -> Option<u64> {
-    let (ranges, _) = parse_ranges(input);
-
+pub fn part_two(inventory: &Inventory) -> Option<u64> {
     Some(
-        ranges
-            .into_iter() //
+        inventory
+            .ranges
+            .iter() //
             .map(|(start, end)| end - start + 1)
             .sum(),
     )
@@ -77,7 +91,8 @@ mod tests {
 
     #[test]
     fn test_part_one_example_1() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let input = advent_of_code::template::read_year_file("examples", YEAR, DAY);
+        let result = part_one(&parse(&input));
         assert_eq!(result, Some(3));
     }
 
@@ -102,7 +117,8 @@ mod tests {
 
     #[test]
     fn test_part_two_example_1() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let input = advent_of_code::template::read_year_file("examples", YEAR, DAY);
+        let result = part_two(&parse(&input));
         assert_eq!(result, Some(14));
     }
 }
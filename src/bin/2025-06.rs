@@ -1,6 +1,6 @@
 use itertools::enumerate;
 
-advent_of_code::solution!(6);
+advent_of_code::solution!(2025, 6);
 
 pub fn part_one(input: &str) -> Option<u64> {
     let (numbers, operators) = parse_input(input);
@@ -110,13 +110,13 @@ mod tests {
 
     #[test]
     fn test_part_one_example_1() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&advent_of_code::template::read_year_file("examples", YEAR, DAY));
         assert_eq!(result, Some(4277556));
     }
 
     #[test]
     fn test_part_two_example_1() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&advent_of_code::template::read_year_file("examples", YEAR, DAY));
         assert_eq!(result, Some(3263827));
     }
 }
@@ -2,11 +2,13 @@ use std::collections::{HashMap, HashSet};
 
 use advent_of_code::util::grid::Grid;
 
-advent_of_code::solution!(7);
+advent_of_code::solution!(2025, 7, parse = parse);
 
-pub fn part_one(input: &str) -> Option<u64> {
-    let grid = Grid::parse_input(input);
+pub fn parse(input: &str) -> Grid {
+    Grid::parse_input(input)
+}
 
+pub fn part_one(grid: &Grid) -> Option<u64> {
     let start = grid.find(b'S').next().unwrap();
     let start_row = start.1;
     let mut beams = HashSet::from([start.0]);
@@ -32,9 +34,7 @@ pub fn part_one(input: &str) -> Option<u64> {
     Some(splits)
 }
 
-pub fn part_two(input: &str) -> Option<u64> {
-    let grid = Grid::parse_input(input);
-
+pub fn part_two(grid: &Grid) -> Option<u64> {
     let start = grid.find(b'S').next().unwrap();
     let start_row = start.1;
     let mut beams = HashMap::from([(start.0, 1)]);
@@ -64,13 +64,15 @@ mod tests {
 
     #[test]
     fn test_part_one_example_1() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let input = advent_of_code::template::read_year_file("examples", YEAR, DAY);
+        let result = part_one(&parse(&input));
         assert_eq!(result, Some(21));
     }
 
     #[test]
     fn test_part_two_example_1() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let input = advent_of_code::template::read_year_file("examples", YEAR, DAY);
+        let result = part_two(&parse(&input));
         assert_eq!(result, Some(40));
     }
 }
@@ -2,10 +2,10 @@ use std::{cmp::Reverse, collections::{BinaryHeap, HashMap, HashSet}};
 
 use advent_of_code::util::union_find::UnionFind;
 
-advent_of_code::solution!(8);
+advent_of_code::solution!(2025, 8, parse = parse);
 
 #[derive(PartialEq, Eq, PartialOrd, Ord, Clone, Hash)]
-struct Box(u64, u64, u64);
+pub struct Box(u64, u64, u64);
 
 impl Box {
     fn parse(line: &str) -> Self {
@@ -28,16 +28,26 @@ impl Box {
     }
 }
 
-pub fn part_one(input: &str) -> Option<u64> {
-    part_one_with_sample(input, 1000)
+pub struct Playground {
+    boxes: Vec<Box>,
 }
 
-pub fn part_one_with_sample(input: &str, samples: usize) -> Option<u64> {
-    let boxes: Vec<Box> = input
-        .lines()
-        .filter(|line| !line.is_empty())
-        .map(Box::parse)
-        .collect();
+pub fn parse(input: &str) -> Playground {
+    Playground {
+        boxes: input
+            .lines()
+            .filter(|line| !line.is_empty())
+            .map(Box::parse)
+            .collect(),
+    }
+}
+
+pub fn part_one(playground: &Playground) -> Option<u64> {
+    part_one_with_sample(playground, 1000)
+}
+
+pub fn part_one_with_sample(playground: &Playground, samples: usize) -> Option<u64> {
+    let boxes = &playground.boxes;
 
     let mut heap: BinaryHeap<(u64, Box, Box)> = BinaryHeap::new();
 
@@ -78,12 +88,8 @@ pub fn part_one_with_sample(input: &str, samples: usize) -> Option<u64> {
     )
 }
 
-pub fn part_two(input: &str) -> Option<u64> {
-    let boxes: Vec<Box> = input
-        .lines()
-        .filter(|line| !line.is_empty())
-        .map(Box::parse)
-        .collect();
+pub fn part_two(playground: &Playground) -> Option<u64> {
+    let boxes = &playground.boxes;
 
     let mut heap: BinaryHeap<Reverse<(u64, Box, Box)>> = BinaryHeap::new();
 
@@ -123,14 +129,15 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result =
-            part_one_with_sample(&advent_of_code::template::read_file("examples", DAY), 10);
+        let input = advent_of_code::template::read_year_file("examples", YEAR, DAY);
+        let result = part_one_with_sample(&parse(&input), 10);
         assert_eq!(result, Some(40));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let input = advent_of_code::template::read_year_file("examples", YEAR, DAY);
+        let result = part_two(&parse(&input));
         assert_eq!(result, Some(25272));
     }
 }
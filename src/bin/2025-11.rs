@@ -1,6 +1,6 @@
 use std::collections::HashMap;
 
-advent_of_code::solution!(11);
+advent_of_code::solution!(2025, 11);
 
 pub fn part_one(input: &str) -> Option<u64> {
     let devices: HashMap<&str, Vec<&str>> = input.lines()
@@ -81,16 +81,16 @@ mod tests {
 
     #[test]
     fn test_part_one_example_1() {
-        let result = part_one(&advent_of_code::template::read_file_part(
-            "examples", DAY, 1,
+        let result = part_one(&advent_of_code::template::read_year_file_part(
+            "examples", YEAR, DAY, 1,
         ));
         assert_eq!(result, Some(5));
     }
 
     #[test]
     fn test_part_two_example_2() {
-        let result = part_two(&advent_of_code::template::read_file_part(
-            "examples", DAY, 2,
+        let result = part_two(&advent_of_code::template::read_year_file_part(
+            "examples", YEAR, DAY, 2,
         ));
         assert_eq!(result, Some(2));
     }
@@ -1,4 +1,6 @@
-use advent_of_code::template::commands::{all, download, read, scaffold, solve, time};
+use advent_of_code::template::commands::{
+    all, download, examples, progress, read, scaffold, solve, time, verify,
+};
 use args::{AppArguments, parse};
 
 #[cfg(feature = "today")]
@@ -7,75 +9,189 @@ use advent_of_code::template::Day;
 use std::process;
 
 mod args {
-    use advent_of_code::template::Day;
+    use advent_of_code::template::commands::scaffold;
+    use advent_of_code::template::{Day, Year, compare, limits::Limits, runner::Options};
     use std::process;
 
     pub enum AppArguments {
         Download {
+            year: Year,
             day: Day,
         },
         Read {
+            year: Year,
             day: Day,
         },
+        Examples {
+            year: Year,
+            day: Day,
+            overwrite: bool,
+        },
         Scaffold {
+            year: Year,
             day: Day,
             download: bool,
             overwrite: bool,
+            template: String,
         },
         Solve {
+            year: Year,
             day: Day,
             release: bool,
             dhat: bool,
             submit: Option<u8>,
+            all_inputs: bool,
+            watch: bool,
         },
         All {
+            year: Year,
             release: bool,
+            in_process: bool,
+            jobs: Option<usize>,
+            limits: Limits,
         },
         Time {
+            year: Year,
             all: bool,
             day: Option<Day>,
             store: bool,
+            in_process: bool,
+            options: Options,
+            compare: Option<f64>,
+        },
+        Verify {
+            year: Year,
+            days: Vec<Day>,
+            in_process: bool,
+        },
+        Progress {
+            year: Year,
         },
         #[cfg(feature = "today")]
-        Today,
+        Today {
+            year: Year,
+        },
     }
 
     pub fn parse() -> Result<AppArguments, Box<dyn std::error::Error>> {
         let mut args = pico_args::Arguments::from_env();
 
-        let app_args = match args.subcommand()?.as_deref() {
-            Some("all") => AppArguments::All {
-                release: args.contains("--release"),
-            },
+        let subcommand = args.subcommand()?;
+        let year = Year::from_arguments(&mut args)?;
+
+        let app_args = match subcommand.as_deref() {
+            Some("all") => {
+                let in_process = args.contains("--in-process");
+                let jobs: Option<usize> = args.opt_value_from_str("--jobs")?;
+
+                if jobs == Some(0) {
+                    eprintln!("`--jobs` must be at least 1.");
+                    process::exit(1);
+                }
+
+                if jobs.is_some() && in_process {
+                    eprintln!("`--jobs` can not be combined with `--in-process`.");
+                    process::exit(1);
+                }
+
+                AppArguments::All {
+                    year,
+                    release: args.contains("--release"),
+                    in_process,
+                    jobs,
+                    limits: Limits::from_arguments(&mut args)?.with_env_defaults(),
+                }
+            }
             Some("time") => {
                 let all = args.contains("--all");
                 let store = args.contains("--store");
+                let in_process = args.contains("--in-process");
+                let mut options = Options::from_arguments(&mut args)?;
+                options.limits = options.limits.with_env_defaults();
+                let threshold: Option<f64> = args.opt_value_from_str("--threshold")?;
+                let compare = args
+                    .contains("--compare")
+                    .then(|| threshold.unwrap_or(compare::DEFAULT_THRESHOLD));
 
                 AppArguments::Time {
+                    year,
                     all,
-                    day: args.opt_free_from_str()?,
+                    day: args
+                        .opt_free_from_str()?
+                        .map(|day| year.check_day(day))
+                        .transpose()?,
                     store,
+                    in_process,
+                    options,
+                    compare,
+                }
+            }
+            Some("verify") => {
+                let in_process = args.contains("--in-process");
+                let mut days = vec![];
+                while let Some(day) = args.opt_free_from_str()? {
+                    days.push(year.check_day(day)?);
+                }
+
+                AppArguments::Verify {
+                    year,
+                    days,
+                    in_process,
                 }
             }
+            Some("progress") => AppArguments::Progress { year },
             Some("download") => AppArguments::Download {
-                day: args.free_from_str()?,
+                year,
+                day: year.check_day(args.free_from_str()?)?,
             },
             Some("read") => AppArguments::Read {
-                day: args.free_from_str()?,
+                year,
+                day: year.check_day(args.free_from_str()?)?,
+            },
+            Some("examples") => AppArguments::Examples {
+                year,
+                day: year.check_day(args.free_from_str()?)?,
+                overwrite: args.contains("--overwrite"),
             },
             Some("scaffold") => AppArguments::Scaffold {
-                day: args.free_from_str()?,
+                year,
+                day: year.check_day(args.free_from_str()?)?,
                 download: args.contains("--download"),
                 overwrite: args.contains("--overwrite"),
+                template: args
+                    .opt_value_from_str("--template")?
+                    .unwrap_or_else(|| scaffold::DEFAULT_TEMPLATE.into()),
             },
-            Some("solve") => AppArguments::Solve {
-                day: args.free_from_str()?,
-                release: args.contains("--release"),
-                submit: args.opt_value_from_str("--submit")?,
-                dhat: args.contains("--dhat"),
-            },
+            Some("solve") => {
+                let submit = args.opt_value_from_str("--submit")?;
+                let all_inputs = args.contains("--all-inputs");
+                let watch = args.contains("--watch");
+                let dhat = args.contains("--dhat");
+
+                if submit.is_some() && all_inputs {
+                    eprintln!("`--submit` can not be combined with `--all-inputs`.");
+                    process::exit(1);
+                }
+
+                if watch && (submit.is_some() || all_inputs || dhat) {
+                    eprintln!(
+                        "`--watch` can not be combined with `--submit`, `--all-inputs` or `--dhat`."
+                    );
+                    process::exit(1);
+                }
+
+                AppArguments::Solve {
+                    year,
+                    day: year.check_day(args.free_from_str()?)?,
+                    release: args.contains("--release"),
+                    submit,
+                    dhat,
+                    all_inputs,
+                    watch,
+                }
+            }
             #[cfg(feature = "today")]
-            Some("today") => AppArguments::Today,
+            Some("today") => AppArguments::Today { year },
             Some(x) => {
                 eprintln!("Unknown command: {x}");
                 process::exit(1);
@@ -102,38 +218,80 @@ fn main() {
             std::process::exit(1);
         }
         Ok(args) => match args {
-            AppArguments::All { release } => all::handle(release),
-            AppArguments::Time { day, all, store } => time::handle(day, all, store),
-            AppArguments::Download { day } => download::handle(day),
-            AppArguments::Read { day } => read::handle(day),
+            AppArguments::All {
+                year,
+                release,
+                in_process,
+                jobs,
+                limits,
+            } => all::handle(year, release, in_process, jobs, limits),
+            AppArguments::Time {
+                year,
+                day,
+                all,
+                store,
+                in_process,
+                options,
+                compare,
+            } => time::handle(year, day, all, store, in_process, options, compare),
+            AppArguments::Verify {
+                year,
+                days,
+                in_process,
+            } => verify::handle(year, &days, in_process),
+            AppArguments::Progress { year } => progress::handle(year),
+            AppArguments::Download { year, day } => download::handle(year, day),
+            AppArguments::Read { year, day } => read::handle(year, day),
+            AppArguments::Examples {
+                year,
+                day,
+                overwrite,
+            } => examples::handle(year, day, overwrite),
             AppArguments::Scaffold {
+                year,
                 day,
                 download,
                 overwrite,
+                template,
             } => {
-                scaffold::handle(day, overwrite);
+                // NOTE: downloading first fills in the puzzle title, scaffolding keeps the downloaded input.
                 if download {
-                    download::handle(day);
+                    download::handle(year, day);
+                }
+                scaffold::handle(year, day, overwrite, &template);
+                if download {
+                    examples::handle(year, day, false);
                 }
             }
             AppArguments::Solve {
+                year,
                 day,
                 release,
                 dhat,
                 submit,
-            } => solve::handle(day, release, dhat, submit),
+                all_inputs,
+                watch,
+            } => {
+                if watch {
+                    solve::watch(year, day, release);
+                } else {
+                    solve::handle(year, day, release, dhat, submit, all_inputs);
+                }
+            }
             #[cfg(feature = "today")]
-            AppArguments::Today => {
-                match Day::today() {
+            AppArguments::Today { year } => {
+                match Day::today(year) {
                     Some(day) => {
-                        scaffold::handle(day, false);
-                        download::handle(day);
-                        read::handle(day)
+                        download::handle(year, day);
+                        scaffold::handle(year, day, false, scaffold::DEFAULT_TEMPLATE);
+                        examples::handle(year, day, false);
+                        read::handle(year, day)
                     }
                     None => {
                         eprintln!(
                             "`today` command can only be run between the 1st and \
-                            the 25th of december. Please use `scaffold` with a specific day."
+                            the {}th of december. Please use `scaffold` with a specific day.",
+                            year.day_count()
                         );
                         process::exit(1)
                     }
@@ -0,0 +1,229 @@
+/// Answers returned by solution parts, and how they are shown and submitted.
+///
+/// Parts can return any type that implements [`ToAnswer`]: numbers, `char`s, strings, rendered
+/// [`Grid`]s, as well as [`Option`]s and [`Result`]s of these. Other types that implement
+/// [`Display`] can be returned wrapped in [`Displayed`]. Letters drawn in rendered answers
+/// are read with [`ocr`], so that they can be submitted like any other answer. Not to be confused
+/// with the ledger of submitted answers in [`answers`](crate::template::answers).
+use std::fmt::{self, Display};
+
+use crate::util::grid::{Grid, ToChar};
+use crate::util::ocr;
+
+/// Normalized answer of a solution part.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Answer {
+    /// A single-line answer that can be submitted as is.
+    Value(String),
+    /// Multi-line output, e.g. letters drawn on a grid, with the letters it shows if they
+    /// could be recognized.
+    Rendered {
+        output: String,
+        letters: Option<String>,
+    },
+    /// The part did not return an answer.
+    Unsolved,
+    /// The part returned an error.
+    Failed(String),
+}
+
+impl Answer {
+    /// Normalize a text answer. Surrounding whitespace is not part of single-line answers,
+    /// multi-line answers keep their indentation but lose surrounding empty lines.
+    pub fn from_text(text: &str) -> Self {
+        let trimmed = text.trim();
+        if trimmed.contains('\n') {
+            Answer::rendered(text.trim_matches('\n').trim_end().to_string())
+        } else {
+            Answer::Value(trimmed.to_string())
+        }
+    }
+
+    /// A multi-line answer, with the letters it draws if there are any.
+    pub fn rendered(output: String) -> Self {
+        let letters = ocr::recognize(&output);
+        Answer::Rendered { output, letters }
+    }
+
+    /// The string to submit, if the answer can be submitted without reading it first.
+    pub fn submission(&self) -> Option<&str> {
+        match self {
+            Answer::Value(value) => Some(value),
+            Answer::Rendered { letters, .. } => letters.as_deref(),
+            _ => None,
+        }
+    }
+
+    /// The text of the answer, if the part was solved. Recognized letters take the place of the
+    /// drawing they were read from.
+    pub fn text(&self) -> Option<&str> {
+        match self {
+            Answer::Value(text) => Some(text),
+            Answer::Rendered { output, letters } => Some(letters.as_deref().unwrap_or(output)),
+            Answer::Unsolved | Answer::Failed(_) => None,
+        }
+    }
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Value(text) | Answer::Rendered { output: text, .. } => f.write_str(text),
+            Answer::Unsolved => f.write_str("✖"),
+            Answer::Failed(reason) => write!(f, "failed: {reason}"),
+        }
+    }
+}
+
+/// Conversion of the return value of a solution part into an [`Answer`].
+pub trait ToAnswer {
+    fn to_answer(&self) -> Answer;
+}
+
+macro_rules! value_answers {
+    ($($t:ty),*) => {
+        $(
+            impl ToAnswer for $t {
+                fn to_answer(&self) -> Answer {
+                    Answer::Value(self.to_string())
+                }
+            }
+        )*
+    };
+}
+
+value_answers!(
+    u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64, char, bool
+);
+
+/// Wraps a part's return value that only implements [`Display`], e.g. like
+/// `Some(Displayed(position))`. The value is shown and submitted as it's formatted.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Displayed<T>(pub T);
+
+impl<T: Display> ToAnswer for Displayed<T> {
+    fn to_answer(&self) -> Answer {
+        Answer::from_text(&self.0.to_string())
+    }
+}
+
+impl ToAnswer for str {
+    fn to_answer(&self) -> Answer {
+        Answer::from_text(self)
+    }
+}
+
+impl ToAnswer for String {
+    fn to_answer(&self) -> Answer {
+        Answer::from_text(self)
+    }
+}
+
+impl<T: ToChar> ToAnswer for Grid<T> {
+    fn to_answer(&self) -> Answer {
+        Answer::rendered(self.to_string())
+    }
+}
+
+impl ToAnswer for Answer {
+    fn to_answer(&self) -> Answer {
+        self.clone()
+    }
+}
+
+impl<T: ToAnswer + ?Sized> ToAnswer for &T {
+    fn to_answer(&self) -> Answer {
+        (**self).to_answer()
+    }
+}
+
+impl<T: ToAnswer> ToAnswer for Option<T> {
+    fn to_answer(&self) -> Answer {
+        match self {
+            Some(value) => value.to_answer(),
+            None => Answer::Unsolved,
+        }
+    }
+}
+
+impl<T: ToAnswer, E: Display> ToAnswer for Result<T, E> {
+    fn to_answer(&self) -> Answer {
+        match self {
+            Ok(value) => value.to_answer(),
+            Err(e) => Answer::Failed(e.to_string()),
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::fmt::{self, Display};
+
+    use super::{Answer, Displayed, ToAnswer};
+    use crate::util::grid::Grid;
+
+    #[test]
+    fn normalizes_values() {
+        assert_eq!(Some(42_u64).to_answer(), Answer::Value("42".into()));
+        assert_eq!((-7_i32).to_answer(), Answer::Value("-7".into()));
+        assert_eq!(" abc\n".to_answer(), Answer::Value("abc".into()));
+        assert_eq!(None::<u64>.to_answer(), Answer::Unsolved);
+        assert_eq!(Some(42_u64).to_answer().submission(), Some("42"));
+        assert_eq!(1.5_f64.to_answer(), Answer::Value("1.5".into()));
+        assert_eq!('x'.to_answer(), Answer::Value("x".into()));
+    }
+
+    #[test]
+    fn formats_displayed_values() {
+        struct Position(i32, i32);
+
+        impl Display for Position {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, "{},{}", self.0, self.1)
+            }
+        }
+
+        let answer = Some(Displayed(Position(6, -1))).to_answer();
+
+        assert_eq!(answer, Answer::Value("6,-1".into()));
+        assert_eq!(answer.submission(), Some("6,-1"));
+    }
+
+    #[test]
+    fn reports_errors() {
+        let answer = Err::<u64, _>("no path found").to_answer();
+
+        assert_eq!(answer, Answer::Failed("no path found".into()));
+        assert_eq!(answer.to_string(), "failed: no path found");
+        assert_eq!(answer.text(), None);
+    }
+
+    #[test]
+    fn renders_multi_line_answers() {
+        let grid = Grid::parse_input("#..#\n####\n#..#\n");
+        let answer = Ok::<_, String>(grid).to_answer();
+
+        assert_eq!(answer, Answer::rendered("#..#\n####\n#..#".into()));
+        assert_eq!(answer.submission(), None);
+        assert_eq!(answer.text(), Some("#..#\n####\n#..#"));
+        assert_eq!(
+            "\n  #\n ##\n".to_string().to_answer(),
+            Answer::Rendered {
+                output: "  #\n ##".into(),
+                letters: None
+            }
+        );
+    }
+
+    #[test]
+    fn recognizes_drawn_letters() {
+        let drawing = "#..#.###.\n#..#..#..\n####..#..\n#..#..#..\n#..#..#..\n#..#.###.";
+        let answer = Grid::parse_input(drawing).to_answer();
+
+        assert_eq!(answer.submission(), Some("HI"));
+        assert_eq!(answer.text(), Some("HI"));
+        assert_eq!(answer.to_string(), drawing);
+    }
+}
@@ -0,0 +1,402 @@
+/// Ledger of the answers that were submitted for a day, stored in `data/{year}/answers/{day}.json`.
+///
+/// The ledger guards submissions: answers that are known to be wrong, or that fall outside of the
+/// bounds of earlier "too high" / "too low" verdicts, are not submitted again. Once a part is
+/// solved, new results are only compared to the correct answer.
+use std::{collections::HashMap, fmt::Display, fs, io::Error, str::FromStr};
+use tinyjson::JsonValue;
+
+use crate::template::client::{Hint, Verdict};
+use crate::template::{Day, Year, data_path};
+
+/// Verdict of a submitted answer, as stored in the ledger.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Outcome {
+    Correct,
+    TooHigh,
+    TooLow,
+    /// The answer is wrong, without a hint in which direction.
+    Wrong,
+    /// The answer was not checked because another answer was submitted too recently.
+    RateLimited,
+}
+
+impl Outcome {
+    /// Maps the verdict of a submission. Verdicts that do not tell anything about the answer return [`None`].
+    pub fn from_verdict(verdict: &Verdict) -> Option<Self> {
+        match verdict {
+            Verdict::Correct => Some(Outcome::Correct),
+            Verdict::Incorrect(Some(Hint::TooHigh)) => Some(Outcome::TooHigh),
+            Verdict::Incorrect(Some(Hint::TooLow)) => Some(Outcome::TooLow),
+            Verdict::Incorrect(None) => Some(Outcome::Wrong),
+            Verdict::TooSoon(_) => Some(Outcome::RateLimited),
+            Verdict::WrongLevel | Verdict::Unknown => None,
+        }
+    }
+
+    fn as_str(self) -> &'static str {
+        match self {
+            Outcome::Correct => "correct",
+            Outcome::TooHigh => "too_high",
+            Outcome::TooLow => "too_low",
+            Outcome::Wrong => "wrong",
+            Outcome::RateLimited => "rate_limited",
+        }
+    }
+}
+
+impl FromStr for Outcome {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "correct" => Ok(Outcome::Correct),
+            "too_high" => Ok(Outcome::TooHigh),
+            "too_low" => Ok(Outcome::TooLow),
+            "wrong" => Ok(Outcome::Wrong),
+            "rate_limited" => Ok(Outcome::RateLimited),
+            _ => Err(format!("unknown outcome `{s}`.")),
+        }
+    }
+}
+
+impl Display for Outcome {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Outcome::Correct => "correct",
+            Outcome::TooHigh => "too high",
+            Outcome::TooLow => "too low",
+            Outcome::Wrong => "wrong",
+            Outcome::RateLimited => "rate-limited",
+        })
+    }
+}
+
+/// Prefix of the paragraphs that reveal the answers of solved parts in a puzzle description.
+const PUZZLE_ANSWER_PREFIX: &str = "Your puzzle answer was `";
+
+/// Answers of the solved parts revealed in a puzzle description, in order of the parts.
+/// Puzzle descriptions only include them when they are downloaded after solving a part.
+pub fn puzzle_answers(markdown: &str) -> Vec<String> {
+    markdown
+        .lines()
+        .filter_map(|line| line.trim().strip_prefix(PUZZLE_ANSWER_PREFIX))
+        .filter_map(|rest| rest.split_once('`'))
+        .map(|(answer, _)| answer.to_string())
+        .collect()
+}
+
+/// A single submitted answer.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Attempt {
+    pub part: u8,
+    pub answer: String,
+    pub outcome: Outcome,
+}
+
+/// Result of checking an answer against the ledger before submitting it.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Check {
+    /// Nothing is known about the answer, it can be submitted.
+    Submit,
+    /// The part is solved and the answer matches the correct one.
+    Confirmed,
+    /// The part is solved with a different answer.
+    Differs { correct: String },
+    /// The answer was already submitted and was wrong.
+    KnownWrong(Outcome),
+    /// The answer is not lower than an answer that was too high.
+    AboveBound { bound: String },
+    /// The answer is not higher than an answer that was too low.
+    BelowBound { bound: String },
+}
+
+impl Display for Check {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Check::Submit => write!(f, "The answer has not been submitted yet."),
+            Check::Confirmed => write!(f, "✔ The answer matches the known correct answer."),
+            Check::Differs { correct } => {
+                write!(
+                    f,
+                    "✖ The answer differs from the known correct answer {correct}."
+                )
+            }
+            Check::KnownWrong(outcome) => {
+                write!(f, "✖ The answer was already submitted and was {outcome}.")
+            }
+            Check::AboveBound { bound } => {
+                write!(f, "✖ The answer is too high: {bound} already was.")
+            }
+            Check::BelowBound { bound } => {
+                write!(f, "✖ The answer is too low: {bound} already was.")
+            }
+        }
+    }
+}
+
+/// All answers submitted for a day, in order of submission.
+#[derive(Clone, Debug, Default)]
+pub struct Ledger {
+    pub attempts: Vec<Attempt>,
+}
+
+impl Ledger {
+    /// Rehydrate the ledger of a day. If not present, returns an empty ledger.
+    pub fn read_from_file(year: Year, day: Day) -> Result<Self, String> {
+        match fs::read_to_string(ledger_path(year, day)) {
+            Ok(contents) => Ledger::try_from(contents),
+            Err(_) => Ok(Ledger::default()),
+        }
+    }
+
+    /// Dehydrate the ledger of a day to its JSON file.
+    pub fn store_file(&self, year: Year, day: Day) -> Result<(), Error> {
+        let path = ledger_path(year, day);
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let json = JsonValue::from(self);
+        let mut file = fs::File::create(path)?;
+        json.format_to(&mut file)
+    }
+
+    pub fn record(&mut self, part: u8, answer: &str, outcome: Outcome) {
+        self.attempts.push(Attempt {
+            part,
+            answer: answer.trim().to_string(),
+            outcome,
+        });
+    }
+
+    /// The correct answer of a part, if it was submitted.
+    pub fn correct_answer(&self, part: u8) -> Option<&str> {
+        self.attempts
+            .iter()
+            .find(|a| a.part == part && a.outcome == Outcome::Correct)
+            .map(|a| a.answer.as_str())
+    }
+
+    /// The correct answer of a part, taken from the ledger or, if not submitted, from the downloaded
+    /// puzzle description.
+    pub fn known_answer(&self, year: Year, day: Day, part: u8) -> Option<String> {
+        if let Some(answer) = self.correct_answer(part) {
+            return Some(answer.to_string());
+        }
+
+        let puzzle = fs::read_to_string(data_path(year, "puzzles", &format!("{day}.md"))).ok()?;
+        puzzle_answers(&puzzle)
+            .into_iter()
+            .nth(usize::from(part).checked_sub(1)?)
+    }
+
+    /// Check an answer against the known verdicts of a part.
+    pub fn check(&self, part: u8, answer: &str) -> Check {
+        let answer = answer.trim();
+
+        if let Some(correct) = self.correct_answer(part) {
+            return if correct == answer {
+                Check::Confirmed
+            } else {
+                Check::Differs {
+                    correct: correct.to_string(),
+                }
+            };
+        }
+
+        let attempts = self.attempts.iter().filter(|a| a.part == part);
+
+        if let Some(attempt) = attempts
+            .clone()
+            .find(|a| a.answer == answer && a.outcome != Outcome::RateLimited)
+        {
+            return Check::KnownWrong(attempt.outcome);
+        }
+
+        // NOTE: bounds only apply to numeric answers.
+        let Ok(value) = answer.parse::<i128>() else {
+            return Check::Submit;
+        };
+
+        let numeric = |outcome: Outcome| {
+            attempts
+                .clone()
+                .filter(move |a| a.outcome == outcome)
+                .filter_map(|a| a.answer.parse::<i128>().ok())
+        };
+
+        if let Some(bound) = numeric(Outcome::TooHigh).filter(|x| value >= *x).min() {
+            return Check::AboveBound {
+                bound: bound.to_string(),
+            };
+        }
+
+        if let Some(bound) = numeric(Outcome::TooLow).filter(|x| value <= *x).max() {
+            return Check::BelowBound {
+                bound: bound.to_string(),
+            };
+        }
+
+        Check::Submit
+    }
+}
+
+fn ledger_path(year: Year, day: Day) -> std::path::PathBuf {
+    data_path(year, "answers", &format!("{day}.json"))
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&Ledger> for JsonValue {
+    fn from(value: &Ledger) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert(
+            "attempts".into(),
+            JsonValue::Array(value.attempts.iter().map(JsonValue::from).collect()),
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<String> for Ledger {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let json = JsonValue::from_str(&value).or(Err("not valid JSON file."))?;
+
+        let json_attempts = json
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("expected JSON document to be an object.")?
+            .get("attempts")
+            .ok_or("expected JSON document to have key `attempts`.")?
+            .get::<Vec<JsonValue>>()
+            .ok_or("expected `json.attempts` to be an array.")?;
+
+        Ok(Ledger {
+            attempts: json_attempts
+                .iter()
+                .map(Attempt::try_from)
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+impl From<&Attempt> for JsonValue {
+    fn from(value: &Attempt) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert("part".into(), JsonValue::Number(f64::from(value.part)));
+        map.insert("answer".into(), JsonValue::String(value.answer.clone()));
+        map.insert(
+            "outcome".into(),
+            JsonValue::String(value.outcome.as_str().into()),
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for Attempt {
+    type Error = String;
+
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected attempt to be a JSON object.")?;
+
+        let part = json
+            .get("part")
+            .and_then(|v| v.get::<f64>())
+            .map(|part| *part as u8)
+            .ok_or("Expected attempt.part to be a number.")?;
+
+        let answer = json
+            .get("answer")
+            .and_then(|v| v.get::<String>())
+            .ok_or("Expected attempt.answer to be a string.")?;
+
+        let outcome = json
+            .get("outcome")
+            .and_then(|v| v.get::<String>())
+            .ok_or("Expected attempt.outcome to be a string.")?
+            .parse()?;
+
+        Ok(Attempt {
+            part,
+            answer: answer.clone(),
+            outcome,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{Check, Ledger, Outcome, puzzle_answers};
+
+    fn ledger() -> Ledger {
+        let mut ledger = Ledger::default();
+        ledger.record(1, "100", Outcome::TooHigh);
+        ledger.record(1, "10", Outcome::TooLow);
+        ledger.record(1, "50", Outcome::Wrong);
+        ledger.record(1, "60", Outcome::RateLimited);
+        ledger.record(2, "abc", Outcome::Correct);
+        ledger
+    }
+
+    #[test]
+    fn refuses_known_wrong_answers() {
+        assert_eq!(ledger().check(1, "50\n"), Check::KnownWrong(Outcome::Wrong));
+        assert_eq!(
+            ledger().check(1, "100"),
+            Check::KnownWrong(Outcome::TooHigh)
+        );
+    }
+
+    #[test]
+    fn refuses_answers_outside_of_bounds() {
+        assert_eq!(
+            ledger().check(1, "120"),
+            Check::AboveBound {
+                bound: "100".into()
+            }
+        );
+        assert_eq!(
+            ledger().check(1, "-3"),
+            Check::BelowBound { bound: "10".into() }
+        );
+        assert_eq!(ledger().check(1, "99"), Check::Submit);
+        assert_eq!(ledger().check(1, "60"), Check::Submit);
+        assert_eq!(ledger().check(1, "text"), Check::Submit);
+    }
+
+    #[test]
+    fn confirms_solved_parts() {
+        assert_eq!(ledger().check(2, "abc"), Check::Confirmed);
+        assert_eq!(
+            ledger().check(2, "abd"),
+            Check::Differs {
+                correct: "abc".into()
+            }
+        );
+    }
+
+    #[test]
+    fn reads_answers_from_puzzles() {
+        let markdown = "## --- Day 1 ---\n\nText with `code`.\n\nYour puzzle answer was `42`.\n\n\
+            ## --- Part Two ---\n\nYour puzzle answer was `ABC`.\n";
+        assert_eq!(puzzle_answers(markdown), vec!["42", "ABC"]);
+    }
+
+    #[test]
+    fn serializes_to_json() {
+        let json = tinyjson::JsonValue::from(&ledger()).stringify().unwrap();
+        let parsed = Ledger::try_from(json).unwrap();
+
+        assert_eq!(parsed.attempts, ledger().attempts);
+    }
+}
@@ -0,0 +1,255 @@
+/// Benchmark settings and the statistics computed from benchmark samples.
+use std::{collections::HashMap, time::Duration};
+use tinyjson::JsonValue;
+
+/// Controls how long and how often a part is benched.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BenchOptions {
+    /// Maximum number of runs that are executed before sampling starts and are not recorded.
+    pub warmup: u128,
+    /// Approximate total time spent sampling a single part.
+    pub budget: Duration,
+    pub min_samples: u128,
+    pub max_samples: u128,
+}
+
+impl Default for BenchOptions {
+    fn default() -> Self {
+        Self {
+            warmup: 10,
+            budget: Duration::from_secs(1),
+            min_samples: 10,
+            max_samples: 10_000,
+        }
+    }
+}
+
+impl BenchOptions {
+    /// Number of warmup runs, given the duration of a first run. Warming up takes at most a tenth
+    /// of the budget, so slow parts are not run [`warmup`](Self::warmup) times before sampling.
+    pub fn warmup_count(&self, base_time: &Duration) -> u128 {
+        (self.budget.as_nanos() / 10 / base_time.as_nanos().max(10)).min(self.warmup)
+    }
+
+    /// Number of samples to take, given the duration of a first run. This is always at least 1.
+    pub fn sample_count(&self, base_time: &Duration) -> u128 {
+        (self.budget.as_nanos() / base_time.as_nanos().max(10))
+            .clamp(self.min_samples, self.max_samples.max(self.min_samples))
+            .max(1)
+    }
+
+    /// Converts the options to command-line arguments, as parsed by [`Options::from_args`](crate::template::runner::Options::from_args).
+    pub fn to_args(&self) -> Vec<String> {
+        vec![
+            "--warmup".into(),
+            self.warmup.to_string(),
+            "--budget-ms".into(),
+            self.budget.as_millis().to_string(),
+            "--min-samples".into(),
+            self.min_samples.to_string(),
+            "--max-samples".into(),
+            self.max_samples.to_string(),
+        ]
+    }
+}
+
+/// Summary of the samples of a benched part.
+///
+/// Samples outside of the [Tukey fences](https://en.wikipedia.org/wiki/Outlier#Tukey's_fences)
+/// (1.5 IQR below the first or above the third quartile) are counted as outliers and are not
+/// included in the other values.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BenchStats {
+    pub mean: Duration,
+    pub median: Duration,
+    pub min: Duration,
+    pub p95: Duration,
+    pub stddev: Duration,
+    /// Number of recorded samples, including outliers.
+    pub samples: u128,
+    pub outliers: u128,
+}
+
+impl BenchStats {
+    /// Computes statistics from a list of samples. Returns `None` if there are no samples.
+    #[allow(
+        clippy::cast_possible_truncation,
+        clippy::cast_precision_loss,
+        clippy::cast_sign_loss
+    )]
+    pub fn from_samples(samples: &[Duration]) -> Option<Self> {
+        if samples.is_empty() {
+            return None;
+        }
+
+        let mut sorted: Vec<u128> = samples.iter().map(Duration::as_nanos).collect();
+        sorted.sort_unstable();
+
+        let q1 = percentile(&sorted, 0.25);
+        let q3 = percentile(&sorted, 0.75);
+        let fence = (q3 - q1) * 1.5;
+        let (low, high) = (q1 - fence, q3 + fence);
+
+        let kept: Vec<u128> = sorted
+            .iter()
+            .copied()
+            .filter(|&x| (low..=high).contains(&(x as f64)))
+            .collect();
+
+        let n = kept.len() as f64;
+        let mean = kept.iter().map(|&x| x as f64).sum::<f64>() / n;
+        let variance = kept.iter().map(|&x| (x as f64 - mean).powi(2)).sum::<f64>() / n;
+
+        let nanos = |x: f64| Duration::from_nanos(x.round() as u64);
+
+        Some(Self {
+            mean: nanos(mean),
+            median: nanos(percentile(&kept, 0.5)),
+            min: nanos(kept[0] as f64),
+            p95: nanos(percentile(&kept, 0.95)),
+            stddev: nanos(variance.sqrt()),
+            samples: sorted.len() as u128,
+            outliers: (sorted.len() - kept.len()) as u128,
+        })
+    }
+}
+
+/// Linear interpolation between the closest ranks of a sorted, non-empty list.
+#[allow(
+    clippy::cast_possible_truncation,
+    clippy::cast_precision_loss,
+    clippy::cast_sign_loss
+)]
+fn percentile(sorted: &[u128], p: f64) -> f64 {
+    let rank = p * (sorted.len() - 1) as f64;
+    let (lower, upper) = (rank.floor() as usize, rank.ceil() as usize);
+    let weight = rank - rank.floor();
+    sorted[lower] as f64 * (1.0 - weight) + sorted[upper] as f64 * weight
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&BenchStats> for JsonValue {
+    #[allow(clippy::cast_precision_loss)]
+    fn from(value: &BenchStats) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        for (key, duration) in [
+            ("mean_nanos", value.mean),
+            ("median_nanos", value.median),
+            ("min_nanos", value.min),
+            ("p95_nanos", value.p95),
+            ("stddev_nanos", value.stddev),
+        ] {
+            map.insert(key.into(), JsonValue::Number(duration.as_nanos() as f64));
+        }
+
+        map.insert("samples".into(), JsonValue::Number(value.samples as f64));
+        map.insert("outliers".into(), JsonValue::Number(value.outliers as f64));
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for BenchStats {
+    type Error = String;
+
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected stats to be a JSON object.")?;
+
+        let number = |key: &str| {
+            json.get(key)
+                .and_then(|v| v.get::<f64>())
+                .copied()
+                .ok_or(format!("Expected stats.{key} to be a number."))
+        };
+
+        let duration = |key: &str| number(key).map(|x| Duration::from_nanos(x as u64));
+
+        Ok(BenchStats {
+            mean: duration("mean_nanos")?,
+            median: duration("median_nanos")?,
+            min: duration("min_nanos")?,
+            p95: duration("p95_nanos")?,
+            stddev: duration("stddev_nanos")?,
+            samples: number("samples")? as u128,
+            outliers: number("outliers")? as u128,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::time::Duration;
+
+    use tinyjson::JsonValue;
+
+    use super::{BenchOptions, BenchStats};
+
+    fn nanos(values: &[u64]) -> Vec<Duration> {
+        values.iter().map(|&x| Duration::from_nanos(x)).collect()
+    }
+
+    #[test]
+    fn computes_stats() {
+        let stats = BenchStats::from_samples(&nanos(&[10, 20, 30, 40, 50])).unwrap();
+        assert_eq!(stats.mean, Duration::from_nanos(30));
+        assert_eq!(stats.median, Duration::from_nanos(30));
+        assert_eq!(stats.min, Duration::from_nanos(10));
+        assert_eq!(stats.p95, Duration::from_nanos(48));
+        assert_eq!(stats.stddev, Duration::from_nanos(14));
+        assert_eq!(stats.samples, 5);
+        assert_eq!(stats.outliers, 0);
+    }
+
+    #[test]
+    fn rejects_outliers() {
+        let stats = BenchStats::from_samples(&nanos(&[10, 11, 10, 12, 11, 10, 10, 5000])).unwrap();
+        assert_eq!(stats.samples, 8);
+        assert_eq!(stats.outliers, 1);
+        assert_eq!(stats.median, Duration::from_nanos(10));
+        assert_eq!(stats.mean, Duration::from_nanos(11));
+        assert_eq!(stats.p95, Duration::from_nanos(12));
+    }
+
+    #[test]
+    fn handles_single_sample() {
+        let stats = BenchStats::from_samples(&nanos(&[42])).unwrap();
+        assert_eq!(stats.median, Duration::from_nanos(42));
+        assert_eq!(stats.p95, Duration::from_nanos(42));
+        assert_eq!(stats.stddev, Duration::ZERO);
+    }
+
+    #[test]
+    fn handles_no_samples() {
+        assert_eq!(BenchStats::from_samples(&[]), None);
+    }
+
+    #[test]
+    fn round_trips_json() {
+        let stats = BenchStats::from_samples(&nanos(&[10, 20, 30, 40, 50])).unwrap();
+        let json = JsonValue::from(&stats);
+        assert_eq!(BenchStats::try_from(&json).unwrap(), stats);
+    }
+
+    #[test]
+    fn bounds_sample_count() {
+        let options = BenchOptions::default();
+        assert_eq!(options.sample_count(&Duration::from_secs(1)), 10);
+        assert_eq!(options.sample_count(&Duration::from_millis(1)), 1000);
+        assert_eq!(options.sample_count(&Duration::from_nanos(1)), 10_000);
+    }
+
+    #[test]
+    fn bounds_warmup_count() {
+        let options = BenchOptions::default();
+        assert_eq!(options.warmup_count(&Duration::from_secs(2)), 0);
+        assert_eq!(options.warmup_count(&Duration::from_millis(20)), 5);
+        assert_eq!(options.warmup_count(&Duration::from_micros(1)), 10);
+    }
+}
@@ -0,0 +1,442 @@
+/// Built-in client for the Advent of Code website.
+///
+/// Downloads inputs and puzzle descriptions and submits answers on behalf of the user identified by
+/// their session cookie. The base URL can be changed with [`BASE_URL_ENV`], e.g. to test against a
+/// local server.
+use std::{env, fmt::Display, fs, path::PathBuf};
+
+use crate::template::{Day, Year, markdown};
+
+/// Environment variable that selects the [`Backend`].
+pub const BACKEND_ENV: &str = "AOC_BACKEND";
+
+/// Environment variable that overrides the URL of the Advent of Code website.
+pub const BASE_URL_ENV: &str = "AOC_BASE_URL";
+
+/// Environment variable that holds the session cookie. Takes precedence over the session file.
+pub const SESSION_ENV: &str = "AOC_SESSION";
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+/// Name of the file in the home directory that holds the session cookie. Shared with aoc-cli.
+const SESSION_FILE: &str = ".adventofcode.session";
+
+const USER_AGENT: &str = concat!(
+    "advent_of_code/",
+    env!("CARGO_PKG_VERSION"),
+    " (+https://github.com/fspoettel/advent-of-code-rust)"
+);
+
+/// Tool that the `download`, `read` and `submit` commands use to talk to Advent of Code.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Backend {
+    /// The built-in [`Client`].
+    Native,
+    /// The external `aoc` command-line, see [`aoc_cli`](crate::template::aoc_cli).
+    AocCli,
+}
+
+impl Backend {
+    /// Reads the backend from the `AOC_BACKEND` environment variable. Defaults to [`Backend::Native`].
+    pub fn from_env() -> Self {
+        match env::var(BACKEND_ENV).as_deref() {
+            Ok("aoc-cli") => Backend::AocCli,
+            _ => Backend::Native,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum ClientError {
+    MissingSession,
+    /// The session cookie was rejected.
+    Unauthorized,
+    /// The puzzle does not exist or is not unlocked yet.
+    NotFound,
+    BadStatus(u16),
+    Transport(String),
+}
+
+impl Display for ClientError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ClientError::MissingSession => write!(
+                f,
+                "no session cookie found. Set `{SESSION_ENV}` or create the file `~/{SESSION_FILE}`."
+            ),
+            ClientError::Unauthorized => write!(f, "the session cookie was rejected."),
+            ClientError::NotFound => write!(f, "the puzzle is not available (yet)."),
+            ClientError::BadStatus(status) => write!(f, "unexpected response status {status}."),
+            ClientError::Transport(e) => write!(f, "request failed: {e}"),
+        }
+    }
+}
+
+/// Description of a puzzle, converted to markdown.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Puzzle {
+    /// Title of the puzzle, e.g. `Day 1: Secret Entrance`.
+    pub title: Option<String>,
+    /// The description of all unlocked parts, including the answers of solved parts.
+    pub markdown: String,
+}
+
+impl Puzzle {
+    /// Extracts the puzzle description from the HTML of a puzzle page.
+    pub fn from_html(html: &str) -> Self {
+        Self::from_markdown(fragments_to_markdown(html))
+    }
+
+    /// Reads the title of a puzzle description that was already converted to markdown,
+    /// e.g. a downloaded puzzle file.
+    pub fn from_markdown(markdown: String) -> Self {
+        // NOTE: aoc-cli escapes the dashes of headings as `\---`.
+        let title = markdown
+            .lines()
+            .find_map(|line| {
+                line.trim_start_matches(['#', ' ', '\\'])
+                    .strip_prefix("--- ")
+            })
+            .and_then(|line| line.strip_suffix(" ---"))
+            .map(ToString::to_string);
+
+        Self { title, markdown }
+    }
+}
+
+/// Whether a wrong answer was too high or too low.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Hint {
+    TooHigh,
+    TooLow,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    Incorrect(Option<Hint>),
+    /// An answer was submitted too recently. Holds the remaining time, e.g. `4m 32s`.
+    TooSoon(Option<String>),
+    /// The part is locked or was already solved.
+    WrongLevel,
+    Unknown,
+}
+
+/// Response to a submitted answer.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Submission {
+    pub verdict: Verdict,
+    /// The message of the response, converted to markdown.
+    pub message: String,
+}
+
+impl Verdict {
+    /// Reads the verdict from the text of a submission response.
+    /// Line breaks are ignored, so this also works with the wrapped output of aoc-cli.
+    pub fn from_message(message: &str) -> Self {
+        let message = message.split_whitespace().collect::<Vec<_>>().join(" ");
+
+        if message.contains("That's the right answer") {
+            Verdict::Correct
+        } else if message.contains("That's not the right answer") {
+            Verdict::Incorrect(if message.contains("too high") {
+                Some(Hint::TooHigh)
+            } else if message.contains("too low") {
+                Some(Hint::TooLow)
+            } else {
+                None
+            })
+        } else if message.contains("You gave an answer too recently") {
+            Verdict::TooSoon(
+                message
+                    .split_once("You have ")
+                    .and_then(|(_, rest)| rest.split_once(" left to wait"))
+                    .map(|(wait, _)| wait.to_string()),
+            )
+        } else if message.contains("You don't seem to be solving the right level") {
+            Verdict::WrongLevel
+        } else {
+            Verdict::Unknown
+        }
+    }
+}
+
+impl Submission {
+    /// Reads the verdict from the HTML of a submission response.
+    pub fn from_html(html: &str) -> Self {
+        let message = fragments_to_markdown(html);
+        let verdict = Verdict::from_message(&message);
+        Self { verdict, message }
+    }
+}
+
+fn fragments_to_markdown(html: &str) -> String {
+    fragments(html)
+        .into_iter()
+        .map(markdown::from_html)
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// The `<article>` elements of a page and the paragraphs that reveal answers of solved parts, in order.
+fn fragments(html: &str) -> Vec<&str> {
+    const ELEMENTS: [(&str, &str); 2] = [
+        ("<article", "</article>"),
+        ("<p>Your puzzle answer was", "</p>"),
+    ];
+
+    let mut fragments = vec![];
+    let mut rest = html;
+
+    while let Some((start, end)) = ELEMENTS
+        .iter()
+        .filter_map(|(open, close)| rest.find(open).map(|start| (start, *close)))
+        .min_by_key(|(start, _)| *start)
+    {
+        let len = rest[start..]
+            .find(end)
+            .map_or(rest.len() - start, |x| x + end.len());
+        fragments.push(&rest[start..start + len]);
+        rest = &rest[start + len..];
+    }
+
+    fragments
+}
+
+/* -------------------------------------------------------------------------- */
+
+pub struct Client {
+    base_url: String,
+    session: String,
+    agent: ureq::Agent,
+}
+
+impl Client {
+    pub fn new(base_url: &str, session: &str) -> Self {
+        Self {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.trim().to_string(),
+            agent: ureq::AgentBuilder::new().user_agent(USER_AGENT).build(),
+        }
+    }
+
+    /// Creates a client from the environment.
+    /// The session is read from `AOC_SESSION` or the session file, the base URL from `AOC_BASE_URL`.
+    pub fn from_env() -> Result<Self, ClientError> {
+        let session = env::var(SESSION_ENV)
+            .ok()
+            .or_else(|| fs::read_to_string(session_file()?).ok())
+            .filter(|session| !session.trim().is_empty())
+            .ok_or(ClientError::MissingSession)?;
+
+        let base_url = env::var(BASE_URL_ENV).unwrap_or_else(|_| DEFAULT_BASE_URL.into());
+
+        Ok(Self::new(&base_url, &session))
+    }
+
+    /// Downloads the puzzle input of a day.
+    pub fn input(&self, year: Year, day: Day) -> Result<String, ClientError> {
+        self.get(&format!("/{year}/day/{}/input", day.into_inner()))
+    }
+
+    /// Downloads the description of a day.
+    pub fn puzzle(&self, year: Year, day: Day) -> Result<Puzzle, ClientError> {
+        let html = self.get(&format!("/{year}/day/{}", day.into_inner()))?;
+        Ok(Puzzle::from_html(&html))
+    }
+
+    /// Submits the answer of one part of a day.
+    pub fn submit(
+        &self,
+        year: Year,
+        day: Day,
+        part: u8,
+        answer: &str,
+    ) -> Result<Submission, ClientError> {
+        let url = format!("{}/{year}/day/{}/answer", self.base_url, day.into_inner());
+        let response = self
+            .agent
+            .post(&url)
+            .set("Cookie", &self.cookie())
+            .send_form(&[("level", &part.to_string()), ("answer", answer)]);
+        let html = read_response(response)?;
+        Ok(Submission::from_html(&html))
+    }
+
+    fn get(&self, path: &str) -> Result<String, ClientError> {
+        let response = self
+            .agent
+            .get(&format!("{}{path}", self.base_url))
+            .set("Cookie", &self.cookie())
+            .call();
+        read_response(response)
+    }
+
+    fn cookie(&self) -> String {
+        format!("session={}", self.session)
+    }
+}
+
+fn read_response(response: Result<ureq::Response, ureq::Error>) -> Result<String, ClientError> {
+    match response {
+        Ok(response) => response
+            .into_string()
+            .map_err(|e| ClientError::Transport(e.to_string())),
+        // NOTE: inputs of other users respond with 400 when the session is not valid.
+        Err(ureq::Error::Status(400 | 401 | 403, _)) => Err(ClientError::Unauthorized),
+        Err(ureq::Error::Status(404, _)) => Err(ClientError::NotFound),
+        Err(ureq::Error::Status(status, _)) => Err(ClientError::BadStatus(status)),
+        Err(ureq::Error::Transport(e)) => Err(ClientError::Transport(e.to_string())),
+    }
+}
+
+fn session_file() -> Option<PathBuf> {
+    env::var_os("HOME")
+        .or_else(|| env::var_os("USERPROFILE"))
+        .map(|home| PathBuf::from(home).join(SESSION_FILE))
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::{
+        io::{BufRead, BufReader, Read, Write},
+        net::TcpListener,
+        thread::{self, JoinHandle},
+    };
+
+    use super::{Client, ClientError, Hint, Puzzle, Submission, Verdict};
+    use crate::{day, year};
+
+    const PUZZLE_HTML: &str = r#"<html><body><main>
+<article class="day-desc"><h2>--- Day 1: Test ---</h2><p>Count the <em>numbers</em>.</p></article>
+<p>Your puzzle answer was <code>42</code>.</p>
+<article class="day-desc"><h2 id="part2">--- Part Two ---</h2><p>Sum them.</p></article>
+<form method="post" action="1/answer"><input type="text" name="answer"/></form>
+</main></body></html>"#;
+
+    /// Serves a single response and returns the base URL and the raw request it received.
+    fn serve_once(status: &'static str, body: &'static str) -> (String, JoinHandle<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+
+        let handle = thread::spawn(move || {
+            let (stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(stream);
+
+            let mut request = String::new();
+            let mut content_length = 0;
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                if let Some(len) = line.to_lowercase().strip_prefix("content-length: ") {
+                    content_length = len.trim().parse().unwrap();
+                }
+                request.push_str(&line);
+                if line == "\r\n" {
+                    break;
+                }
+            }
+            let mut content = vec![0; content_length];
+            reader.read_exact(&mut content).unwrap();
+            request.push_str(&String::from_utf8(content).unwrap());
+
+            let response = format!(
+                "HTTP/1.1 {status}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                body.len()
+            );
+            reader.get_mut().write_all(response.as_bytes()).unwrap();
+            request
+        });
+
+        (base_url, handle)
+    }
+
+    #[test]
+    fn downloads_inputs() {
+        let (base_url, server) = serve_once("200 OK", "1\n2\n3\n");
+        let client = Client::new(&base_url, "abc\n");
+
+        let input = client.input(year!(2024), day!(1)).unwrap();
+        let request = server.join().unwrap();
+
+        assert_eq!(input, "1\n2\n3\n");
+        assert!(request.starts_with("GET /2024/day/1/input "));
+        assert!(request.contains("session=abc\r\n"));
+    }
+
+    #[test]
+    fn submits_answers() {
+        let (base_url, server) = serve_once(
+            "200 OK",
+            "<main><article><p>That's the right answer! You are one gold star closer.</p></article></main>",
+        );
+        let client = Client::new(&base_url, "abc");
+
+        let submission = client.submit(year!(2024), day!(3), 2, "42").unwrap();
+        let request = server.join().unwrap();
+
+        assert_eq!(submission.verdict, Verdict::Correct);
+        assert!(request.starts_with("POST /2024/day/3/answer "));
+        assert!(request.ends_with("level=2&answer=42"));
+    }
+
+    #[test]
+    fn maps_error_statuses() {
+        let (base_url, server) = serve_once("404 Not Found", "");
+        let client = Client::new(&base_url, "abc");
+
+        let result = client.puzzle(year!(2024), day!(25));
+        server.join().unwrap();
+
+        assert!(matches!(result, Err(ClientError::NotFound)));
+    }
+
+    #[test]
+    fn extracts_puzzles() {
+        let puzzle = Puzzle::from_html(PUZZLE_HTML);
+        assert_eq!(puzzle.title.as_deref(), Some("Day 1: Test"));
+        assert_eq!(
+            Puzzle::from_markdown("\\--- Day 2: Other ---\n".into())
+                .title
+                .as_deref(),
+            Some("Day 2: Other")
+        );
+        assert_eq!(
+            puzzle.markdown,
+            "## --- Day 1: Test ---\n\nCount the *numbers*.\n\n\
+            Your puzzle answer was `42`.\n\n\
+            ## --- Part Two ---\n\nSum them.\n"
+        );
+    }
+
+    #[test]
+    fn reads_submission_verdicts() {
+        let verdict = |message: &str| {
+            Submission::from_html(&format!("<article><p>{message}</p></article>")).verdict
+        };
+
+        assert_eq!(
+            verdict("That's not the right answer; your answer is too high."),
+            Verdict::Incorrect(Some(Hint::TooHigh))
+        );
+        assert_eq!(
+            verdict("That's not the right answer. If you're stuck, ..."),
+            Verdict::Incorrect(None)
+        );
+        assert_eq!(
+            verdict("You gave an answer too recently. You have 4m 32s left to wait."),
+            Verdict::TooSoon(Some("4m 32s".into()))
+        );
+        assert_eq!(
+            verdict("You don't seem to be solving the right level. Did you already complete it?"),
+            Verdict::WrongLevel
+        );
+        assert_eq!(verdict("Something else."), Verdict::Unknown);
+        assert_eq!(
+            Verdict::from_message("That's not the right\nanswer; your answer is too low."),
+            Verdict::Incorrect(Some(Hint::TooLow))
+        );
+    }
+}
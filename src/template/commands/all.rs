@@ -1,5 +1,22 @@
-use crate::template::{all_days, run_multi::run_multi};
+use std::process;
 
-pub fn handle(is_release: bool) {
-    run_multi(&all_days().collect(), is_release, false);
+use crate::template::limits::Limits;
+use crate::template::{Year, all_days, run_multi::run_multi, runner::Options};
+
+pub fn handle(year: Year, is_release: bool, in_process: bool, jobs: Option<usize>, limits: Limits) {
+    let summary = run_multi(
+        year,
+        &all_days(year).collect(),
+        is_release,
+        &Options {
+            limits,
+            ..Options::default()
+        },
+        in_process,
+        jobs,
+    );
+
+    if summary.has_failures() {
+        process::exit(1);
+    }
 }
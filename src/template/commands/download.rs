@@ -1,7 +1,54 @@
-use crate::template::{Day, aoc_cli};
-use std::process;
+use std::{fs, path::Path, process};
 
-pub fn handle(day: Day) {
+use crate::template::client::{Backend, Client};
+use crate::template::{Day, Year, aoc_cli};
+
+pub fn handle(year: Year, day: Day) {
+    match Backend::from_env() {
+        Backend::Native => download(year, day),
+        Backend::AocCli => download_with_aoc_cli(year, day),
+    }
+}
+
+fn download(year: Year, day: Day) {
+    let input_path = format!("data/{year}/inputs/{day}.txt");
+    let puzzle_path = format!("data/{year}/puzzles/{day}.md");
+
+    let client = Client::from_env().unwrap_or_else(|e| {
+        eprintln!("failed to create client: {e}");
+        process::exit(1);
+    });
+
+    let result = client.input(year, day).and_then(|input| {
+        let puzzle = client.puzzle(year, day)?;
+        Ok((input, puzzle))
+    });
+
+    let (input, puzzle) = result.unwrap_or_else(|e| {
+        eprintln!("failed to download day {day}: {e}");
+        process::exit(1);
+    });
+
+    for (path, contents) in [(&input_path, &input), (&puzzle_path, &puzzle.markdown)] {
+        if let Err(e) = write_file(path, contents) {
+            eprintln!("failed to write \"{path}\": {e}");
+            process::exit(1);
+        }
+    }
+
+    println!("---");
+    println!("🎄 Successfully wrote input to \"{}\".", &input_path);
+    println!("🎄 Successfully wrote puzzle to \"{}\".", &puzzle_path);
+}
+
+fn write_file(path: &str, contents: &str) -> Result<(), std::io::Error> {
+    if let Some(parent) = Path::new(path).parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(path, contents)
+}
+
+fn download_with_aoc_cli(year: Year, day: Day) {
     if aoc_cli::check().is_err() {
         eprintln!(
             "command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it."
@@ -9,7 +56,7 @@ pub fn handle(day: Day) {
         process::exit(1);
     }
 
-    if let Err(e) = aoc_cli::download(day) {
+    if let Err(e) = aoc_cli::download(year, day) {
         eprintln!("failed to call aoc-cli: {e}");
         process::exit(1);
     };
@@ -0,0 +1,75 @@
+use std::{fs, path::Path, process};
+
+use crate::template::examples::Examples;
+use crate::template::{Day, Year, bin_name};
+
+pub fn handle(year: Year, day: Day, overwrite: bool) {
+    let puzzle_path = format!("data/{year}/puzzles/{day}.md");
+    let module_path = format!("src/bin/{}.rs", bin_name(year, day));
+
+    let Ok(puzzle) = fs::read_to_string(&puzzle_path) else {
+        eprintln!("Could not read \"{puzzle_path}\". Run `cargo download {day}` first.");
+        process::exit(1);
+    };
+
+    let examples = Examples::from_markdown(&puzzle);
+
+    if examples.inputs.is_empty() {
+        println!("No examples found in \"{puzzle_path}\".");
+        return;
+    }
+
+    for (index, input) in examples.inputs.iter().enumerate() {
+        let example_path = format!("data/{year}/examples/{}", examples.file_name(day, index));
+
+        // NOTE: scaffolding creates an empty example file, which is safe to replace.
+        let is_empty = fs::read_to_string(&example_path).map_or(true, |x| x.trim().is_empty());
+        if !is_empty && !overwrite {
+            println!("Skipped example file \"{example_path}\", it already exists.");
+            continue;
+        }
+
+        let written = Path::new(&example_path)
+            .parent()
+            .map_or(Ok(()), fs::create_dir_all)
+            .and_then(|()| fs::write(&example_path, input));
+
+        match written {
+            Ok(()) => println!("Wrote example file \"{example_path}\""),
+            Err(e) => {
+                eprintln!("Failed to write example file: {e}");
+                process::exit(1);
+            }
+        }
+    }
+
+    // NOTE: numbered examples replace the empty example file created by scaffolding.
+    let single_path = format!("data/{year}/examples/{day}.txt");
+    if examples.inputs.len() > 1
+        && fs::read_to_string(&single_path).is_ok_and(|x| x.trim().is_empty())
+    {
+        let _ = fs::remove_file(&single_path);
+    }
+
+    for part in [1, 2] {
+        if let Some(answer) = examples.answer(part) {
+            println!("Expected answer of part {part}: {answer}");
+        }
+    }
+
+    // NOTE: the module only exists for scaffolded days.
+    let Ok(module) = fs::read_to_string(&module_path) else {
+        return;
+    };
+
+    let updated = examples.update_tests(&module);
+    if updated != module {
+        match fs::write(&module_path, updated) {
+            Ok(()) => println!("Updated the tests in \"{module_path}\""),
+            Err(e) => {
+                eprintln!("Failed to update module tests: {e}");
+                process::exit(1);
+            }
+        }
+    }
+}
@@ -1,6 +1,9 @@
 pub mod all;
 pub mod download;
+pub mod examples;
+pub mod progress;
 pub mod read;
 pub mod scaffold;
 pub mod solve;
 pub mod time;
+pub mod verify;
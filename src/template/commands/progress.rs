@@ -0,0 +1,35 @@
+use std::process;
+
+use crate::template::Year;
+use crate::template::progress::{self, Progress};
+
+pub fn handle(year: Year) {
+    let read = |year: Year| match Progress::read(year) {
+        Ok(progress) => progress,
+        Err(e) => {
+            eprintln!("{e}");
+            process::exit(1);
+        }
+    };
+
+    println!("{}", read(year).calendar());
+
+    // NOTE: the readme lists every event with data, not only the selected one.
+    let mut years = progress::years_with_data();
+    if !years.contains(&year) {
+        years.push(year);
+        years.sort_unstable_by(|a, b| b.cmp(a));
+    }
+    let events: Vec<Progress> = years.into_iter().map(read).collect();
+
+    println!();
+    match progress::update_readme(&events) {
+        Ok(()) => {
+            println!("Stored updated progress.");
+        }
+        Err(_) => {
+            eprintln!("Failed to store updated progress.");
+            process::exit(1);
+        }
+    }
+}
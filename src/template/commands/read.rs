@@ -1,8 +1,27 @@
 use std::process;
 
-use crate::template::{Day, aoc_cli};
+use crate::template::client::{Backend, Client};
+use crate::template::{Day, Year, aoc_cli};
 
-pub fn handle(day: Day) {
+pub fn handle(year: Year, day: Day) {
+    match Backend::from_env() {
+        Backend::Native => read(year, day),
+        Backend::AocCli => read_with_aoc_cli(year, day),
+    }
+}
+
+fn read(year: Year, day: Day) {
+    let puzzle = Client::from_env()
+        .and_then(|client| client.puzzle(year, day))
+        .unwrap_or_else(|e| {
+            eprintln!("failed to read day {day}: {e}");
+            process::exit(1);
+        });
+
+    print!("{}", puzzle.markdown);
+}
+
+fn read_with_aoc_cli(year: Year, day: Day) {
     if aoc_cli::check().is_err() {
         eprintln!(
             "command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it."
@@ -10,7 +29,7 @@ pub fn handle(day: Day) {
         process::exit(1);
     }
 
-    if let Err(e) = aoc_cli::read(day) {
+    if let Err(e) = aoc_cli::read(year, day) {
         eprintln!("failed to call aoc-cli: {e}");
         process::exit(1);
     };
@@ -1,14 +1,70 @@
 use std::{
-    fs::{File, OpenOptions},
+    fs::{self, File, OpenOptions},
     io::Write,
+    path::Path,
     process,
 };
 
-use crate::template::Day;
+use crate::template::client::Puzzle;
+use crate::template::{Day, Year, bin_name};
 
 const MODULE_TEMPLATE: &str =
     include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/src/template.txt"));
 
+/// Directory of the named templates, e.g. `src/templates/grid.txt` for `--template grid`.
+const TEMPLATES_DIR: &str = "src/templates";
+
+/// Name of the template in `src/template.txt`.
+pub const DEFAULT_TEMPLATE: &str = "default";
+
+/// Names of all templates that can be passed to `--template`.
+fn template_names() -> Vec<String> {
+    let mut names: Vec<String> = fs::read_dir(TEMPLATES_DIR)
+        .into_iter()
+        .flatten()
+        .filter_map(|entry| {
+            let path = entry.ok()?.path();
+            if path.extension()? != "txt" {
+                return None;
+            }
+            path.file_stem()?.to_str().map(String::from)
+        })
+        .collect();
+
+    names.sort();
+    names.insert(0, DEFAULT_TEMPLATE.into());
+    names
+}
+
+fn read_template(name: &str) -> Result<String, String> {
+    if name == DEFAULT_TEMPLATE {
+        return Ok(MODULE_TEMPLATE.into());
+    }
+
+    fs::read_to_string(Path::new(TEMPLATES_DIR).join(format!("{name}.txt"))).map_err(|_| {
+        format!(
+            "unknown template `{name}`. Available templates: {}.",
+            template_names().join(", ")
+        )
+    })
+}
+
+/// Replace the placeholders of a template.
+fn render_template(template: &str, year: Year, day: Day) -> String {
+    // NOTE: the title is only known if the puzzle was downloaded before.
+    let title = fs::read_to_string(format!("data/{year}/puzzles/{day}.md"))
+        .ok()
+        .and_then(|markdown| Puzzle::from_markdown(markdown).title)
+        .unwrap_or_else(|| format!("Day {}", day.into_inner()));
+
+    template
+        .replace("%YEAR_NUMBER%", &year.to_string())
+        .replace("%DAY_NUMBER%", &day.into_inner().to_string())
+        .replace("%PUZZLE_TITLE%", &title)
+        .replace("%INPUT_FILE%", &format!("data/{year}/inputs/{day}.txt"))
+        .replace("%EXAMPLE_FILE%", &format!("data/{year}/examples/{day}.txt"))
+}
+
 fn safe_create_file(path: &str, overwrite: bool) -> Result<File, std::io::Error> {
     let mut file = OpenOptions::new();
     if overwrite {
@@ -19,18 +75,32 @@ fn safe_create_file(path: &str, overwrite: bool) -> Result<File, std::io::Error>
     file.truncate(true).write(true).open(path)
 }
 
-fn create_file(path: &str) -> Result<File, std::io::Error> {
-    OpenOptions::new()
-        .write(true)
-        .create(true)
-        .truncate(true)
-        .open(path)
+/// Create an empty data file. Returns `false` if the file already exists, e.g. because it was downloaded.
+fn create_file(path: &str) -> Result<bool, std::io::Error> {
+    // the data folders of a year are created with its first scaffolded day.
+    if let Some(parent) = Path::new(path).parent() {
+        fs::create_dir_all(parent)?;
+    }
+
+    match OpenOptions::new().write(true).create_new(true).open(path) {
+        Ok(_) => Ok(true),
+        Err(e) if e.kind() == std::io::ErrorKind::AlreadyExists => Ok(false),
+        Err(e) => Err(e),
+    }
 }
 
-pub fn handle(day: Day, overwrite: bool) {
-    let input_path = format!("data/inputs/{day}.txt");
-    let example_path = format!("data/examples/{day}.txt");
-    let module_path = format!("src/bin/{day}.rs");
+pub fn handle(year: Year, day: Day, overwrite: bool, template: &str) {
+    let template = match read_template(template) {
+        Ok(template) => template,
+        Err(e) => {
+            eprintln!("Failed to read template: {e}");
+            process::exit(1);
+        }
+    };
+
+    let input_path = format!("data/{year}/inputs/{day}.txt");
+    let example_path = format!("data/{year}/examples/{day}.txt");
+    let module_path = format!("src/bin/{}.rs", bin_name(year, day));
 
     let mut file = match safe_create_file(&module_path, overwrite) {
         Ok(file) => file,
@@ -40,11 +110,7 @@ pub fn handle(day: Day, overwrite: bool) {
         }
     };
 
-    match file.write_all(
-        MODULE_TEMPLATE
-            .replace("%DAY_NUMBER%", &day.into_inner().to_string())
-            .as_bytes(),
-    ) {
+    match file.write_all(render_template(&template, year, day).as_bytes()) {
         Ok(()) => {
             println!("Created module file \"{}\"", &module_path);
         }
@@ -55,9 +121,12 @@ pub fn handle(day: Day, overwrite: bool) {
     }
 
     match create_file(&input_path) {
-        Ok(_) => {
+        Ok(true) => {
             println!("Created empty input file \"{}\"", &input_path);
         }
+        Ok(false) => {
+            println!("Kept existing input file \"{}\"", &input_path);
+        }
         Err(e) => {
             eprintln!("Failed to create input file: {e}");
             process::exit(1);
@@ -65,15 +134,24 @@ pub fn handle(day: Day, overwrite: bool) {
     }
 
     match create_file(&example_path) {
-        Ok(_) => {
+        Ok(true) => {
             println!("Created empty example file \"{}\"", &example_path);
         }
+        Ok(false) => {
+            println!("Kept existing example file \"{}\"", &example_path);
+        }
         Err(e) => {
             eprintln!("Failed to create example file: {e}");
             process::exit(1);
         }
     }
 
+    let year_arg = if Year::from_env() == Some(year) {
+        String::new()
+    } else {
+        format!(" --year {year}")
+    };
+
     println!("---");
-    println!("🎄 Type `cargo solve {day}` to run your solution.");
+    println!("🎄 Type `cargo solve {day}{year_arg}` to run your solution.");
 }
@@ -1,9 +1,34 @@
 use std::process::{Command, Stdio};
+use std::{thread, time::Duration};
 
-use crate::template::Day;
+use crate::template::input_sets::ALL_INPUTS_FLAG;
+use crate::template::record::PartRecord;
+use crate::template::run_multi::child_commands;
+use crate::template::runner::Options;
+use crate::template::watch::{Snapshot, diff_answers};
+use crate::template::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET, Day, YEAR_ENV, Year, bin_name};
 
-pub fn handle(day: Day, release: bool, dhat: bool, submit_part: Option<u8>) {
-    let mut cmd_args = vec!["run".to_string(), "--bin".to_string(), day.to_string()];
+/// Interval in which watched files are checked for changes.
+const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// Time to wait for further changes after a change, as editors may write a file in several steps.
+const SETTLE_TIME: Duration = Duration::from_millis(200);
+
+/// Name filters of the tests that check a solution against its examples.
+const EXAMPLE_TESTS: [&str; 2] = ["test_part_", "example"];
+
+/// Name filter of the tests that check a solution against the real input.
+const SOLUTION_TESTS: &str = "_solution";
+
+pub fn handle(
+    year: Year,
+    day: Day,
+    release: bool,
+    dhat: bool,
+    submit_part: Option<u8>,
+    all_inputs: bool,
+) {
+    let mut cmd_args = vec!["run".to_string(), "--bin".to_string(), bin_name(year, day)];
 
     if dhat {
         cmd_args.extend([
@@ -23,8 +48,13 @@ pub fn handle(day: Day, release: bool, dhat: bool, submit_part: Option<u8>) {
         cmd_args.push(submit_part.to_string());
     }
 
+    if all_inputs {
+        cmd_args.push(ALL_INPUTS_FLAG.to_string());
+    }
+
     let mut cmd = Command::new("cargo")
         .args(&cmd_args)
+        .env(YEAR_ENV, year.to_string())
         .stdout(Stdio::inherit())
         .stderr(Stdio::inherit())
         .spawn()
@@ -32,3 +62,103 @@ pub fn handle(day: Day, release: bool, dhat: bool, submit_part: Option<u8>) {
 
     cmd.wait().unwrap();
 }
+
+/// Re-run the example tests and the solution of a day whenever its solution, the `util` modules
+/// or its data files change. Runs until interrupted.
+pub fn watch(year: Year, day: Day, release: bool) {
+    let mut snapshot = Snapshot::of_day(year, day);
+    let mut previous: Option<Vec<PartRecord>> = None;
+
+    loop {
+        println!("{ANSI_BOLD}Tests{ANSI_RESET}");
+        println!("------");
+        run_tests(year, day, release);
+
+        println!();
+        println!("{ANSI_BOLD}Day {day}{ANSI_RESET}");
+        println!("------");
+
+        let records = child_commands::run_solution(year, day, &Options::default(), release)
+            .unwrap_or_default();
+
+        if records.is_empty() {
+            println!("Not solved.");
+        } else {
+            if let Some(previous) = &previous {
+                println!();
+                println!("{ANSI_BOLD}Changes{ANSI_RESET}");
+                for line in diff_answers(previous, &records) {
+                    println!("{line}");
+                }
+            }
+            previous = Some(records);
+        }
+
+        println!();
+        println!("{ANSI_ITALIC}Watching for changes, press Ctrl+C to stop.{ANSI_RESET}");
+
+        let changes = loop {
+            thread::sleep(POLL_INTERVAL);
+            let current = Snapshot::of_day(year, day);
+            let changes = current.changes(&snapshot);
+            if !changes.is_empty() {
+                break changes;
+            }
+        };
+
+        thread::sleep(SETTLE_TIME);
+        snapshot = Snapshot::of_day(year, day);
+
+        println!();
+        for path in changes {
+            println!("Changed: {}", path.display());
+        }
+        println!();
+    }
+}
+
+/// Run the example tests of a solution: `test_part_one` and `test_part_two` of the templates and
+/// tests named after them or after examples, but not the `_solution` tests that read the real input.
+/// Prints a one-line summary if they pass and the full output otherwise.
+fn run_tests(year: Year, day: Day, release: bool) {
+    let mut cmd_args = vec![
+        "test".to_string(),
+        "--quiet".to_string(),
+        "--bin".to_string(),
+        bin_name(year, day),
+    ];
+
+    if release {
+        cmd_args.push("--release".to_string());
+    }
+
+    cmd_args.push("--".to_string());
+    cmd_args.extend(EXAMPLE_TESTS.map(String::from));
+    cmd_args.extend(["--skip".to_string(), SOLUTION_TESTS.to_string()]);
+
+    let output = match Command::new("cargo")
+        .args(&cmd_args)
+        .stdin(Stdio::null())
+        .output()
+    {
+        Ok(output) => output,
+        Err(e) => {
+            eprintln!("Failed to run tests: {e}");
+            return;
+        }
+    };
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+
+    if output.status.success() {
+        let summary = stdout
+            .lines()
+            .find(|line| line.starts_with("test result:"))
+            .unwrap_or("test result: ok.");
+        println!("✔ {summary}");
+    } else {
+        print!("{stdout}");
+        eprint!("{}", String::from_utf8_lossy(&output.stderr));
+        println!("✖ Tests failed.");
+    }
+}
@@ -1,30 +1,59 @@
-use std::collections::HashSet;
+use std::{collections::HashSet, process};
 
+use crate::template::compare;
 use crate::template::run_multi::run_multi;
-use crate::template::timings::Timings;
-use crate::template::{Day, all_days, readme_benchmarks};
+use crate::template::runner::Options;
+use crate::template::timings::StoredTimings;
+use crate::template::{Day, Year, all_days, readme_benchmarks};
 
-pub fn handle(day: Option<Day>, run_all: bool, store: bool) {
-    let stored_timings = Timings::read_from_file();
+pub fn handle(
+    year: Year,
+    day: Option<Day>,
+    run_all: bool,
+    store: bool,
+    in_process: bool,
+    options: Options,
+    compare_threshold: Option<f64>,
+) {
+    let stored_timings = match StoredTimings::read_from_file() {
+        Ok(stored_timings) => stored_timings,
+        Err(e) => {
+            eprintln!("Failed to read stored benchmarks: {e}");
+            process::exit(1);
+        }
+    };
+    let year_timings = stored_timings.get(year);
 
     let days_to_run = day.map_or_else(
         || {
-            if run_all {
-                all_days().collect()
+            // when comparing, re-bench every day since only benched days have a baseline.
+            if run_all || compare_threshold.is_some() {
+                all_days(year).collect()
             } else {
                 // when the `--all` flag is not set, filter out days that are fully benched.
-                all_days()
-                    .filter(|day| !stored_timings.is_day_complete(*day))
+                all_days(year)
+                    .filter(|day| !year_timings.is_day_complete(*day))
                     .collect()
             }
         },
         |day| HashSet::from([day]),
     );
 
-    let timings = run_multi(&days_to_run, true, true).unwrap();
+    let options = Options {
+        timed: true,
+        ..options
+    };
+    // NOTE: days are benched one after another, so that they don't compete for the CPU.
+    let summary = run_multi(year, &days_to_run, true, &options, in_process, None);
+    let has_failures = summary.has_failures();
+    let timings = summary.timings.unwrap();
+
+    let has_regressions = compare_threshold.is_some_and(|threshold| {
+        compare::print_report(&compare::compare(&year_timings, &timings), threshold)
+    });
 
     if store {
-        let merged_timings = stored_timings.merge(&timings);
+        let merged_timings = stored_timings.merge(year, &timings);
         merged_timings.store_file().unwrap();
 
         println!();
@@ -37,4 +66,8 @@ pub fn handle(day: Option<Day>, run_all: bool, store: bool) {
             }
         }
     }
+
+    if has_regressions || has_failures {
+        process::exit(1);
+    }
 }
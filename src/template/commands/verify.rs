@@ -0,0 +1,75 @@
+use std::{collections::HashSet, process};
+
+use crate::template::answers::Ledger;
+use crate::template::run_multi::child_commands;
+use crate::template::runner::Options;
+use crate::template::verify::{self, PartVerification};
+use crate::template::{Day, Year, all_days};
+
+pub fn handle(year: Year, days: &[Day], in_process: bool) {
+    let days_to_check: Vec<Day> = if days.is_empty() {
+        all_days(year).collect()
+    } else {
+        all_days(year).filter(|day| days.contains(day)).collect()
+    };
+
+    let mut expected: Vec<(Day, Vec<(u8, String)>)> = vec![];
+
+    for day in days_to_check {
+        let ledger = match Ledger::read_from_file(year, day) {
+            Ok(ledger) => ledger,
+            Err(e) => {
+                eprintln!("Could not read the answer ledger of day {day}: {e}");
+                process::exit(1);
+            }
+        };
+
+        let answers: Vec<(u8, String)> = [1, 2]
+            .into_iter()
+            .filter_map(|part| Some((part, ledger.known_answer(year, day, part)?)))
+            .collect();
+
+        if answers.is_empty() {
+            if days.contains(&day) {
+                println!("Day {day} has no known answers, skipping.");
+            }
+        } else {
+            expected.push((day, answers));
+        }
+    }
+
+    if expected.is_empty() {
+        println!("No solved days to verify.");
+        return;
+    }
+
+    let options = Options::default();
+
+    // NOTE: days that could not be run have no records, so their parts are reported as failed.
+    let records = if in_process {
+        let days_to_run: HashSet<Day> = expected.iter().map(|(day, _)| *day).collect();
+        child_commands::run_in_process(year, &days_to_run, &options, true).unwrap_or_else(|e| {
+            eprintln!("Could not run the `all_days` binary: {e}");
+            vec![]
+        })
+    } else {
+        expected
+            .iter()
+            .flat_map(|(day, _)| {
+                child_commands::run_solution(year, *day, &options, true).unwrap_or_else(|e| {
+                    eprintln!("Could not run day {day}: {e}");
+                    vec![]
+                })
+            })
+            .collect()
+    };
+
+    let verifications: Vec<PartVerification> = expected
+        .iter()
+        .flat_map(|(day, answers)| verify::verify(*day, answers, &records))
+        .collect();
+
+    if verify::print_report(&verifications) {
+        process::exit(1);
+    }
+}
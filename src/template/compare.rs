@@ -0,0 +1,186 @@
+/// Module that compares fresh benchmark timings with the stored ones.
+use std::time::Duration;
+
+use crate::template::record::PARSE_PART;
+use crate::template::timings::{Timing, Timings};
+use crate::template::{ANSI_BOLD, ANSI_RESET, Day};
+
+/// Default regression threshold, in percent.
+pub const DEFAULT_THRESHOLD: f64 = 10.0;
+
+/// Median timings of a single part, before and after.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PartComparison {
+    pub day: Day,
+    /// Part number, or [`PARSE_PART`] for the parse phase.
+    pub part: u8,
+    pub baseline: Option<Duration>,
+    pub current: Duration,
+}
+
+impl PartComparison {
+    /// Relative change to the baseline, in percent. `None` if there is no baseline to compare to.
+    #[allow(clippy::cast_precision_loss)]
+    pub fn delta_percent(&self) -> Option<f64> {
+        let baseline = self.baseline?.as_nanos() as f64;
+        if baseline == 0.0 {
+            return None;
+        }
+        Some((self.current.as_nanos() as f64 - baseline) / baseline * 100.0)
+    }
+
+    pub fn is_regression(&self, threshold: f64) -> bool {
+        self.delta_percent().is_some_and(|delta| delta > threshold)
+    }
+}
+
+fn part_median(timing: &Timing, part: u8) -> Option<Duration> {
+    let stats = match part {
+        PARSE_PART => timing.parse_stats.as_ref(),
+        1 => timing.part_1_stats.as_ref(),
+        _ => timing.part_2_stats.as_ref(),
+    };
+    stats.map(|s| s.median)
+}
+
+/// Compare each benched part of `current` to the same part in `baseline`.
+pub fn compare(baseline: &Timings, current: &Timings) -> Vec<PartComparison> {
+    let mut comparisons = vec![];
+
+    for timing in &current.data {
+        let stored = baseline.data.iter().find(|t| t.day == timing.day);
+
+        for part in [PARSE_PART, 1, 2] {
+            if let Some(current) = part_median(timing, part) {
+                comparisons.push(PartComparison {
+                    day: timing.day,
+                    part,
+                    baseline: stored.and_then(|t| part_median(t, part)),
+                    current,
+                });
+            }
+        }
+    }
+
+    comparisons
+}
+
+/// Print a comparison table. Returns `true` if any part regressed past `threshold`.
+pub fn print_report(comparisons: &[PartComparison], threshold: f64) -> bool {
+    println!("\n{ANSI_BOLD}Comparison{ANSI_RESET} (threshold: {threshold}%)");
+    println!("------");
+
+    for comparison in comparisons {
+        let baseline = comparison
+            .baseline
+            .map_or_else(|| "-".into(), |d| format!("{d:.1?}"));
+
+        let delta = match comparison.delta_percent() {
+            Some(delta) if comparison.is_regression(threshold) => format!("{delta:+.1}% ✖"),
+            Some(delta) => format!("{delta:+.1}%"),
+            None => "new".into(),
+        };
+
+        let part = match comparison.part {
+            PARSE_PART => "Parse".to_string(),
+            part => format!("Part {part}"),
+        };
+
+        println!(
+            "Day {} {part}: {baseline} → {:.1?} ({delta})",
+            comparison.day, comparison.current
+        );
+    }
+
+    let regressions = comparisons
+        .iter()
+        .filter(|c| c.is_regression(threshold))
+        .count();
+
+    if regressions > 0 {
+        println!("\n{regressions} part(s) regressed by more than {threshold}%.");
+    }
+
+    regressions > 0
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::time::Duration;
+
+    use super::{PartComparison, compare};
+    use crate::{
+        day,
+        template::bench::BenchStats,
+        template::timings::{Timing, Timings},
+    };
+
+    fn get_mock_timing(day: u8, part_1: Option<u64>, part_2: Option<u64>) -> Timing {
+        let stats = |nanos: Option<u64>| {
+            nanos.and_then(|n| BenchStats::from_samples(&[Duration::from_nanos(n)]))
+        };
+        Timing {
+            day: crate::template::Day::new(day).unwrap(),
+            part_1: part_1.map(|n| format!("{n}ns")),
+            part_2: part_2.map(|n| format!("{n}ns")),
+            total_nanos: 0_f64,
+            part_1_stats: stats(part_1),
+            part_2_stats: stats(part_2),
+            parse: None,
+            parse_stats: None,
+            failure: None,
+        }
+    }
+
+    #[test]
+    fn compares_matching_parts() {
+        let baseline = Timings {
+            data: vec![get_mock_timing(1, Some(100), Some(200))],
+        };
+        let current = Timings {
+            data: vec![get_mock_timing(1, Some(150), Some(180))],
+        };
+
+        let comparisons = compare(&baseline, &current);
+
+        assert_eq!(comparisons.len(), 2);
+        assert_eq!(comparisons[0].delta_percent(), Some(50.0));
+        assert_eq!(comparisons[1].delta_percent(), Some(-10.0));
+        assert!(comparisons[0].is_regression(10.0));
+        assert!(!comparisons[1].is_regression(10.0));
+    }
+
+    #[test]
+    fn handles_missing_baseline() {
+        let baseline = Timings {
+            data: vec![get_mock_timing(1, None, None)],
+        };
+        let current = Timings {
+            data: vec![
+                get_mock_timing(1, Some(100), None),
+                get_mock_timing(2, Some(100), Some(100)),
+            ],
+        };
+
+        let comparisons = compare(&baseline, &current);
+
+        assert_eq!(comparisons.len(), 3);
+        assert!(comparisons.iter().all(|c| c.baseline.is_none()));
+        assert!(comparisons.iter().all(|c| !c.is_regression(0.0)));
+    }
+
+    #[test]
+    fn respects_threshold() {
+        let comparison = PartComparison {
+            day: day!(1),
+            part: 1,
+            baseline: Some(Duration::from_nanos(100)),
+            current: Duration::from_nanos(105),
+        };
+
+        assert!(comparison.is_regression(4.0));
+        assert!(!comparison.is_regression(5.0));
+    }
+}
@@ -0,0 +1,305 @@
+/// Module that extracts example inputs and their expected answers from downloaded puzzle descriptions.
+///
+/// Examples are the code blocks introduced by a paragraph that mentions an example. The expected
+/// answer of a part is the last emphasized code span of its description, e.g. ``*`11`*``.
+use crate::template::Day;
+
+/// Heading that starts the description of the second part.
+const PART_TWO_HEADING: &str = "## --- Part Two ---";
+
+/// Assertion of the tests of a freshly scaffolded solution.
+const SCAFFOLD_ASSERTION: &str = "assert_eq!(result, None);";
+
+/// Examples and expected answers of a puzzle.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Examples {
+    /// Distinct example inputs, in order of appearance.
+    pub inputs: Vec<String>,
+    /// The expected answer of each part and the index of the example input it belongs to.
+    pub answers: [Option<(usize, String)>; 2],
+}
+
+impl Examples {
+    /// Extract the examples of a puzzle description in markdown.
+    pub fn from_markdown(markdown: &str) -> Self {
+        let mut examples = Examples::default();
+
+        let (part_one, part_two) = markdown
+            .split_once(PART_TWO_HEADING)
+            .unwrap_or((markdown, ""));
+
+        for (index, section) in [part_one, part_two].into_iter().enumerate() {
+            let section = Section::parse(section);
+
+            for input in section.examples {
+                if !examples.inputs.contains(&input) {
+                    examples.inputs.push(input);
+                }
+            }
+
+            // NOTE: the first part always has an example, even if it's not introduced as one.
+            if index == 0 && examples.inputs.is_empty() {
+                examples.inputs.extend(section.first_block);
+            }
+
+            // NOTE: parts without an example of their own refer to the last one.
+            examples.answers[index] = section
+                .answer
+                .filter(|_| !examples.inputs.is_empty())
+                .map(|answer| (examples.inputs.len() - 1, answer));
+        }
+
+        examples
+    }
+
+    /// Name of the example file of an input. Puzzles with a single example use `{day}.txt`, puzzles
+    /// with more examples number them like `{day}-2.txt`, as read by [`read_year_file_part`](crate::template::read_year_file_part).
+    pub fn file_name(&self, day: Day, index: usize) -> String {
+        if self.inputs.len() == 1 {
+            format!("{day}.txt")
+        } else {
+            format!("{day}-{}.txt", index + 1)
+        }
+    }
+
+    /// Fill in the expected answers of the scaffolded tests of a solution.
+    /// Tests that were already changed from the template are left as they are.
+    pub fn update_tests(&self, module: &str) -> String {
+        let mut module = module.to_string();
+
+        for (part, name) in [(1, "test_part_one"), (2, "test_part_two")] {
+            let Some((index, answer)) = &self.answers[part - 1] else {
+                continue;
+            };
+            // NOTE: the template returns `Option<u64>`, other answers have to be filled in by hand.
+            if answer.parse::<u64>().is_err() {
+                continue;
+            }
+
+            let Some(start) = module.find(&format!("fn {name}()")) else {
+                continue;
+            };
+            let end = module[start..]
+                .find("#[test]")
+                .map_or(module.len(), |x| start + x);
+
+            let test = &module[start..end];
+            if !test.contains(SCAFFOLD_ASSERTION) {
+                continue;
+            }
+
+            let mut updated = test.replace(
+                SCAFFOLD_ASSERTION,
+                &format!("assert_eq!(result, Some({answer}));"),
+            );
+            if self.inputs.len() > 1 {
+                updated = updated
+                    .replace(
+                        "read_year_file(\"examples\", YEAR, DAY)",
+                        &format!(
+                            "read_year_file_part(\"examples\", YEAR, DAY, {})",
+                            index + 1
+                        ),
+                    )
+                    .replace(
+                        "read_file(\"examples\", DAY)",
+                        &format!("read_file_part(\"examples\", DAY, {})", index + 1),
+                    );
+            }
+
+            module.replace_range(start..end, &updated);
+        }
+
+        module
+    }
+
+    /// The answer of a part, if it's known.
+    pub fn answer(&self, part: u8) -> Option<&str> {
+        self.answers[usize::from(part).checked_sub(1)?]
+            .as_ref()
+            .map(|(_, answer)| answer.as_str())
+    }
+}
+
+/// Code blocks and emphasized answer of the description of a single part.
+struct Section {
+    examples: Vec<String>,
+    first_block: Option<String>,
+    answer: Option<String>,
+}
+
+impl Section {
+    fn parse(markdown: &str) -> Self {
+        let mut section = Section {
+            examples: vec![],
+            first_block: None,
+            answer: None,
+        };
+
+        let mut paragraph = String::new();
+        let mut paragraph_ended = false;
+        let mut block: Option<String> = None;
+
+        for line in markdown.lines() {
+            match (&mut block, line.starts_with("```")) {
+                (None, true) => block = Some(String::new()),
+                (Some(code), true) => {
+                    let code = std::mem::take(code);
+                    block = None;
+
+                    if paragraph.to_lowercase().contains("example") {
+                        section.examples.push(code.clone());
+                    }
+                    section.first_block.get_or_insert(code);
+                }
+                (Some(code), false) => {
+                    code.push_str(line);
+                    code.push('\n');
+                }
+                (None, false) if line.trim().is_empty() => paragraph_ended = true,
+                (None, false) => {
+                    if line.starts_with("Your puzzle answer was") {
+                        continue;
+                    }
+                    if let Some(answer) = emphasized_code(line).last() {
+                        section.answer = Some(answer.to_string());
+                    }
+                    if paragraph_ended {
+                        paragraph.clear();
+                        paragraph_ended = false;
+                    }
+                    paragraph.push_str(line);
+                    paragraph.push(' ');
+                }
+            }
+        }
+
+        section
+    }
+}
+
+/// All emphasized code spans of a line, e.g. `42` for ``the answer is *`42`*``.
+fn emphasized_code(line: &str) -> Vec<&str> {
+    let mut spans = vec![];
+    let mut rest = line;
+
+    while let Some((_, tail)) = rest.split_once("*`") {
+        let Some((span, tail)) = tail.split_once("`*") else {
+            break;
+        };
+        spans.push(span);
+        rest = tail;
+    }
+
+    spans
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::Examples;
+    use crate::day;
+
+    const PUZZLE: &str = "## --- Day 1: Test ---
+
+Count the numbers.
+
+For example:
+
+```
+1
+2
+```
+
+Here, *`1`* and *`2`* add up to *`3`*.
+
+What is the sum?
+
+Your puzzle answer was `42`.
+
+## --- Part Two ---
+
+Now multiply them. For example:
+
+```
+3
+4
+```
+
+The product is *`12`*.
+";
+
+    #[test]
+    fn extracts_examples_of_each_part() {
+        let examples = Examples::from_markdown(PUZZLE);
+
+        assert_eq!(examples.inputs, vec!["1\n2\n", "3\n4\n"]);
+        assert_eq!(examples.answers[0], Some((0, "3".into())));
+        assert_eq!(examples.answers[1], Some((1, "12".into())));
+    }
+
+    #[test]
+    fn shares_examples_between_parts() {
+        let markdown = PUZZLE.replace("For example:\n\n```\n3\n4\n```", "Using the same example:");
+        let examples = Examples::from_markdown(&markdown);
+
+        assert_eq!(examples.inputs, vec!["1\n2\n"]);
+        assert_eq!(examples.answer(1), Some("3"));
+        assert_eq!(examples.answers[1], Some((0, "12".into())));
+    }
+
+    #[test]
+    fn handles_unsolved_puzzles() {
+        let (part_one, _) = PUZZLE.split_once("## --- Part Two ---").unwrap();
+        let examples = Examples::from_markdown(part_one);
+
+        assert_eq!(examples.inputs.len(), 1);
+        assert_eq!(examples.answer(2), None);
+    }
+
+    #[test]
+    fn falls_back_to_first_block() {
+        let examples =
+            Examples::from_markdown("Consider this:\n\n```\nabc\n```\n\nIt has *`3`* letters.\n");
+
+        assert_eq!(examples.inputs, vec!["abc\n"]);
+        assert_eq!(examples.answer(1), Some("3"));
+    }
+
+    #[test]
+    fn updates_scaffolded_tests() {
+        let template = include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/src/template.txt"));
+        let examples = Examples::from_markdown(PUZZLE);
+
+        let module = examples.update_tests(template);
+
+        assert!(module.contains(
+            "part_one(&advent_of_code::template::read_year_file_part(\"examples\", YEAR, DAY, 1));\n        assert_eq!(result, Some(3));"
+        ));
+        assert!(module.contains(
+            "part_two(&advent_of_code::template::read_year_file_part(\"examples\", YEAR, DAY, 2));\n        assert_eq!(result, Some(12));"
+        ));
+        assert_eq!(examples.file_name(day!(1), 1), "01-2.txt");
+    }
+
+    #[test]
+    fn updates_tests_without_year() {
+        let module = "fn test_part_two() {\n    let result = part_two(&read_file(\"examples\", DAY));\n    assert_eq!(result, None);\n}\n";
+        let examples = Examples::from_markdown(PUZZLE);
+
+        assert!(
+            examples
+                .update_tests(module)
+                .contains("part_two(&read_file_part(\"examples\", DAY, 2));")
+        );
+    }
+
+    #[test]
+    fn keeps_changed_tests() {
+        let module = "fn test_part_one() {\n    assert_eq!(result, Some(5));\n}\n";
+        let examples = Examples::from_markdown(PUZZLE);
+
+        assert_eq!(examples.update_tests(module), module);
+    }
+}
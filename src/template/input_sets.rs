@@ -0,0 +1,282 @@
+/// Named input sets of a day, stored in `data/{year}/inputs/{day}/{name}.txt`.
+///
+/// Each set can have an expected-answer file `{name}.json` next to it, e.g.
+/// `{ "part_1": "1034", "part_2": "6166" }`. Running all sets checks their results against these
+/// answers, so that assumptions which only hold for a single input fail loudly.
+use std::{collections::HashMap, env, fs, path::PathBuf, process, str::FromStr, time::Duration};
+use tinyjson::JsonValue;
+
+use crate::template::record::PartRecord;
+use crate::template::runner::Options;
+use crate::template::{ANSI_BOLD, ANSI_RESET, Day, Year, data_path};
+
+/// Flag that runs a solution against all input sets instead of the default input.
+pub const ALL_INPUTS_FLAG: &str = "--all-inputs";
+
+/// Returns `true` if the current process was started with [`ALL_INPUTS_FLAG`].
+pub fn is_requested() -> bool {
+    env::args().any(|arg| arg == ALL_INPUTS_FLAG)
+}
+
+/// Expected answers of an input set. Parts without an expected answer are not checked.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Expected {
+    pub part_1: Option<String>,
+    pub part_2: Option<String>,
+}
+
+impl Expected {
+    pub fn get(&self, part: u8) -> Option<&str> {
+        match part {
+            1 => self.part_1.as_deref(),
+            2 => self.part_2.as_deref(),
+            _ => None,
+        }
+    }
+}
+
+/// A named input of a day.
+#[derive(Clone, Debug)]
+pub struct InputSet {
+    pub name: String,
+    pub path: PathBuf,
+    /// Expected answers, [`None`] if the set has no expected-answer file.
+    pub expected: Option<Expected>,
+}
+
+impl InputSet {
+    /// All input sets of a day, sorted by name.
+    pub fn discover(year: Year, day: Day) -> Result<Vec<Self>, String> {
+        let dir = data_path(year, "inputs", &day.to_string());
+
+        let mut sets = vec![];
+
+        for entry in
+            fs::read_dir(&dir).map_err(|e| format!("could not read \"{}\": {e}", dir.display()))?
+        {
+            let path = entry.map_err(|e| e.to_string())?.path();
+            if path.extension().is_none_or(|ext| ext != "txt") {
+                continue;
+            }
+
+            let Some(name) = path.file_stem().and_then(|x| x.to_str()) else {
+                continue;
+            };
+
+            let expected = match fs::read_to_string(path.with_extension("json")) {
+                Ok(contents) => {
+                    Some(Expected::try_from(contents).map_err(|e| format!("`{name}.json`: {e}"))?)
+                }
+                Err(_) => None,
+            };
+
+            sets.push(InputSet {
+                name: name.to_string(),
+                path: path.clone(),
+                expected,
+            });
+        }
+
+        sets.sort_unstable_by(|a, b| a.name.cmp(&b.name));
+        Ok(sets)
+    }
+}
+
+/// Result of a part for one input set.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SetResult {
+    pub set: String,
+    pub part: u8,
+    pub expected: Option<String>,
+    pub actual: Option<String>,
+    pub duration: Duration,
+}
+
+impl SetResult {
+    /// Returns `false` if the result differs from the expected answer.
+    /// Results without an expected answer only fail if the part did not return one.
+    pub fn passed(&self) -> bool {
+        match (&self.expected, &self.actual) {
+            (Some(expected), Some(actual)) => actual.trim() == expected,
+            (_, actual) => actual.is_some(),
+        }
+    }
+}
+
+/// Collect the results of the parts run against an input set.
+pub fn collect_results(set: &InputSet, records: &[PartRecord]) -> Vec<SetResult> {
+    records
+        .iter()
+        .filter(|r| r.part == 1 || r.part == 2)
+        .map(|r| SetResult {
+            set: set.name.clone(),
+            part: r.part,
+            expected: set
+                .expected
+                .as_ref()
+                .and_then(|e| e.get(r.part))
+                .map(String::from),
+            actual: r.answer.clone(),
+            duration: r.duration,
+        })
+        .collect()
+}
+
+/// Run the parts of a solution against every input set of the day and print a summary.
+/// Exits with a non-zero status if any part did not match its expected answer.
+pub fn run_all(
+    year: Year,
+    day: Day,
+    run_parts: impl Fn(&str, &Options) -> Vec<PartRecord>,
+    options: &Options,
+) {
+    let sets = match InputSet::discover(year, day) {
+        Ok(sets) if sets.is_empty() => {
+            eprintln!("No input sets found for day {day}.");
+            process::exit(1);
+        }
+        Ok(sets) => sets,
+        Err(e) => {
+            eprintln!("Could not read input sets: {e}");
+            process::exit(1);
+        }
+    };
+
+    let mut results = vec![];
+
+    for set in &sets {
+        println!("{ANSI_BOLD}Input {}{ANSI_RESET}", set.name);
+        println!("------");
+
+        let input = match fs::read_to_string(&set.path) {
+            Ok(input) => input,
+            Err(e) => {
+                eprintln!("Could not read input file {}: {e}", set.path.display());
+                process::exit(1);
+            }
+        };
+
+        results.extend(collect_results(set, &run_parts(&input, options)));
+        println!();
+    }
+
+    if print_report(&results) {
+        process::exit(1);
+    }
+}
+
+/// Print a summary table of all sets. Returns `true` if any part failed.
+fn print_report(results: &[SetResult]) -> bool {
+    println!("{ANSI_BOLD}Summary{ANSI_RESET}");
+    println!("------");
+
+    for result in results {
+        let status = match (&result.expected, result.passed()) {
+            (_, false) => "✖",
+            (Some(_), true) => "✔",
+            (None, true) => "unchecked",
+        };
+
+        println!(
+            "{} Part {}: {} ({:.1?}) {status}",
+            result.set,
+            result.part,
+            format_answer(result.actual.as_deref()),
+            result.duration,
+        );
+
+        if let (Some(expected), false) = (&result.expected, result.passed()) {
+            println!("  expected {}", format_answer(Some(expected)));
+        }
+    }
+
+    let failures = results.iter().filter(|r| !r.passed()).count();
+    if failures > 0 {
+        println!("\n{failures} part(s) failed.");
+    }
+
+    failures > 0
+}
+
+fn format_answer(answer: Option<&str>) -> String {
+    match answer.map(str::trim) {
+        None => "✖".into(),
+        Some(answer) if answer.contains('\n') => format!("▼ ({} lines)", answer.lines().count()),
+        Some(answer) => answer.to_string(),
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl TryFrom<String> for Expected {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let json = JsonValue::from_str(&value).or(Err("not valid JSON file."))?;
+
+        let json = json
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("expected JSON document to be an object.")?;
+
+        // NOTE: answers are strings, numbers would lose precision as JSON floats.
+        let part = |key: &str| match json.get(key) {
+            None | Some(JsonValue::Null) => Ok(None),
+            Some(JsonValue::String(answer)) => Ok(Some(answer.clone())),
+            Some(_) => Err(format!("expected `{key}` to be a string or null.")),
+        };
+
+        Ok(Expected {
+            part_1: part("part_1")?,
+            part_2: part("part_2")?,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{Expected, InputSet, collect_results};
+    use crate::day;
+    use crate::template::record::PartRecord;
+
+    #[test]
+    fn parses_expected_answers() {
+        let expected = Expected::try_from(r#"{ "part_1": "42", "part_2": null }"#.to_string());
+        assert_eq!(
+            expected.unwrap(),
+            Expected {
+                part_1: Some("42".into()),
+                part_2: None
+            }
+        );
+
+        assert!(Expected::try_from(r#"{ "part_1": 42 }"#.to_string()).is_err());
+    }
+
+    #[test]
+    fn checks_results_against_expected_answers() {
+        let set = InputSet {
+            name: "alice".into(),
+            path: "alice.txt".into(),
+            expected: Some(Expected {
+                part_1: Some("42".into()),
+                part_2: None,
+            }),
+        };
+
+        let results = collect_results(
+            &set,
+            &[
+                PartRecord::answered(day!(5), 0, None),
+                PartRecord::answered(day!(5), 1, Some("41")),
+                PartRecord::answered(day!(5), 2, Some("7")),
+            ],
+        );
+
+        assert_eq!(results.len(), 2);
+        assert!(!results[0].passed());
+        assert!(results[1].passed());
+        assert!(!collect_results(&set, &[PartRecord::answered(day!(5), 2, None)])[0].passed());
+    }
+}
@@ -0,0 +1,241 @@
+/// Per-part time limits and memory limits of solution binaries.
+///
+/// Limits are enforced by the solution process itself, so that the time `cargo` spends on
+/// compiling does not count towards them: a watchdog thread exits the process with
+/// [`TIMEOUT_EXIT_CODE`] once the first run of the parse phase or of a part has taken longer than
+/// the part timeout, and the address space of the process is capped with `RLIMIT_AS`, which makes
+/// allocations past the limit abort the process.
+///
+/// The part timeout is not a limit of the whole day: a day with a parse phase and two parts may run
+/// for up to three times as long, and the repeated runs of benching are not limited at all.
+use std::{
+    env, process,
+    sync::{Condvar, Mutex, OnceLock},
+    thread,
+    time::{Duration, Instant},
+};
+
+use crate::template::record::Status;
+
+/// Environment variable that sets the default part timeout, in seconds.
+pub const PART_TIMEOUT_ENV: &str = "AOC_PART_TIMEOUT";
+
+/// Environment variable that sets the default memory limit of a day, in megabytes.
+pub const MEMORY_LIMIT_ENV: &str = "AOC_MEMORY_LIMIT";
+
+/// Exit code of a solution that ran out of time. Same as the one of the `timeout` command.
+pub const TIMEOUT_EXIT_CODE: i32 = 124;
+
+/// Message that the Rust runtime prints when an allocation fails.
+const ALLOCATION_FAILURE: &str = "memory allocation of";
+
+/// The watchdog of the current process, started by [`Limits::apply`].
+static WATCHDOG: OnceLock<Watchdog> = OnceLock::new();
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Limits {
+    /// Maximum duration of the first run of the parse phase or of a part.
+    pub part_timeout: Option<Duration>,
+    /// Maximum size of the address space, in megabytes.
+    pub memory_mb: Option<u64>,
+}
+
+impl Limits {
+    /// Parse `--part-timeout <seconds>` and `--memory-limit <megabytes>` from `args`.
+    pub fn from_arguments(args: &mut pico_args::Arguments) -> Result<Self, pico_args::Error> {
+        let part_timeout: Option<f64> = args.opt_value_from_str("--part-timeout")?;
+        let memory_mb: Option<u64> = args.opt_value_from_str("--memory-limit")?;
+
+        Ok(Self {
+            part_timeout: part_timeout
+                .filter(|secs| secs.is_finite() && *secs > 0.0)
+                .map(Duration::from_secs_f64),
+            memory_mb: memory_mb.filter(|mb| *mb > 0),
+        })
+    }
+
+    /// Fill in the limits that were not passed with `AOC_PART_TIMEOUT` and `AOC_MEMORY_LIMIT`.
+    /// Only commands that run several days use these defaults, `cargo solve` does not.
+    #[must_use]
+    pub fn with_env_defaults(self) -> Self {
+        Self {
+            part_timeout: self.part_timeout.or_else(|| {
+                env::var(PART_TIMEOUT_ENV)
+                    .ok()?
+                    .parse()
+                    .ok()
+                    .filter(|secs: &f64| secs.is_finite() && *secs > 0.0)
+                    .map(Duration::from_secs_f64)
+            }),
+            memory_mb: self.memory_mb.or_else(|| {
+                env::var(MEMORY_LIMIT_ENV)
+                    .ok()?
+                    .parse()
+                    .ok()
+                    .filter(|mb| *mb > 0)
+            }),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.part_timeout.is_none() && self.memory_mb.is_none()
+    }
+
+    /// Converts the limits to command-line arguments of a solution binary.
+    pub fn to_args(&self) -> Vec<String> {
+        let mut args = vec![];
+        if let Some(timeout) = self.part_timeout {
+            args.extend(["--part-timeout".into(), timeout.as_secs_f64().to_string()]);
+        }
+        if let Some(memory_mb) = self.memory_mb {
+            args.extend(["--memory-limit".into(), memory_mb.to_string()]);
+        }
+        args
+    }
+
+    /// Enforce the limits in the current process. The part timeout only applies to runs that are
+    /// passed to [`guard`].
+    pub fn apply(&self) {
+        // NOTE: spawn the watchdog first, its stack counts towards the memory limit.
+        if let Some(timeout) = self.part_timeout {
+            let watchdog = WATCHDOG.get_or_init(|| Watchdog {
+                timeout,
+                deadline: Mutex::new(None),
+                changed: Condvar::new(),
+            });
+            thread::spawn(|| watchdog.watch());
+        }
+
+        if let Some(memory_mb) = self.memory_mb {
+            limit_memory(memory_mb);
+        }
+    }
+}
+
+/// Run `func` under the part timeout of the current process, if [`Limits::apply`] set one.
+pub fn guard<T>(func: impl FnOnce() -> T) -> T {
+    let Some(watchdog) = WATCHDOG.get() else {
+        return func();
+    };
+
+    watchdog.set_deadline(Some(Instant::now() + watchdog.timeout));
+    // NOTE: the deadline is also cleared if `func` panics, panics are reported like any result.
+    let _disarm = Disarm(watchdog);
+    func()
+}
+
+/// Exits the process once the deadline of the guarded run has passed.
+struct Watchdog {
+    timeout: Duration,
+    deadline: Mutex<Option<Instant>>,
+    changed: Condvar,
+}
+
+impl Watchdog {
+    fn set_deadline(&self, deadline: Option<Instant>) {
+        *self.deadline.lock().unwrap() = deadline;
+        self.changed.notify_one();
+    }
+
+    fn watch(&self) {
+        let mut deadline = self.deadline.lock().unwrap();
+        loop {
+            deadline = match *deadline {
+                None => self.changed.wait(deadline).unwrap(),
+                Some(at) if Instant::now() >= at => {
+                    eprintln!("Part timed out after {:.1?}.", self.timeout);
+                    process::exit(TIMEOUT_EXIT_CODE);
+                }
+                Some(at) => {
+                    let remaining = at.saturating_duration_since(Instant::now());
+                    self.changed.wait_timeout(deadline, remaining).unwrap().0
+                }
+            };
+        }
+    }
+}
+
+struct Disarm<'a>(&'a Watchdog);
+
+impl Drop for Disarm<'_> {
+    fn drop(&mut self) {
+        self.0.set_deadline(None);
+    }
+}
+
+#[cfg(unix)]
+fn limit_memory(memory_mb: u64) {
+    let bytes = memory_mb.saturating_mul(1024 * 1024);
+    let limit = libc::rlimit {
+        rlim_cur: bytes,
+        rlim_max: bytes,
+    };
+    // SAFETY: `setrlimit` only reads the passed struct.
+    if unsafe { libc::setrlimit(libc::RLIMIT_AS, &raw const limit) } != 0 {
+        eprintln!("Could not set the memory limit.");
+    }
+}
+
+#[cfg(not(unix))]
+fn limit_memory(_memory_mb: u64) {
+    eprintln!("Memory limits are only supported on unix systems.");
+}
+
+/// Status of a solution process that was stopped by one of its limits, based on its exit code
+/// and the lines it printed to `stderr`.
+pub fn exceeded_status(exit_code: Option<i32>, stderr: &[String]) -> Option<Status> {
+    if exit_code == Some(TIMEOUT_EXIT_CODE) {
+        Some(Status::TimedOut)
+    } else if stderr
+        .iter()
+        .any(|line| line.starts_with(ALLOCATION_FAILURE))
+    {
+        Some(Status::OutOfMemory)
+    } else {
+        None
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::{ffi::OsString, time::Duration};
+
+    use super::{Limits, exceeded_status, guard};
+    use crate::template::record::Status;
+
+    #[test]
+    fn round_trips_arguments() {
+        let limits = Limits {
+            part_timeout: Some(Duration::from_millis(1500)),
+            memory_mb: Some(512),
+        };
+
+        let mut args = pico_args::Arguments::from_vec(
+            limits.to_args().into_iter().map(OsString::from).collect(),
+        );
+
+        assert_eq!(Limits::from_arguments(&mut args).unwrap(), limits);
+    }
+
+    #[test]
+    fn prefers_arguments_over_env_defaults() {
+        let limits = Limits {
+            part_timeout: Some(Duration::from_secs(3)),
+            memory_mb: Some(256),
+        };
+
+        assert_eq!(limits.with_env_defaults(), limits);
+        assert_eq!(guard(|| 42), 42);
+    }
+
+    #[test]
+    fn detects_exceeded_limits() {
+        let oom = vec!["memory allocation of 1024 bytes failed".to_string()];
+
+        assert_eq!(exceeded_status(Some(124), &[]), Some(Status::TimedOut));
+        assert_eq!(exceeded_status(None, &oom), Some(Status::OutOfMemory));
+        assert_eq!(exceeded_status(Some(101), &[]), None);
+    }
+}
@@ -0,0 +1,174 @@
+//! Converts the HTML of Advent of Code puzzle pages to markdown.
+//!
+//! This only handles the small subset of HTML that puzzle descriptions are written in:
+//! headings, paragraphs, code, emphasis, links and lists. All other tags are dropped and their text is kept.
+
+/// Converts an HTML fragment to markdown.
+pub fn from_html(html: &str) -> String {
+    let mut out = String::new();
+    let mut rest = html;
+
+    let mut in_pre = false;
+    let mut in_code = false;
+    let mut code_em = false;
+    let mut links: Vec<String> = vec![];
+
+    while !rest.is_empty() {
+        if let Some(tag) = rest.strip_prefix('<') {
+            let end = tag.find('>').unwrap_or(tag.len());
+            let (name, is_closing, href) = parse_tag(&tag[..end]);
+            rest = tag.get(end + 1..).unwrap_or_default();
+
+            match (name.as_str(), is_closing) {
+                ("h1" | "h2" | "h3", false) => {
+                    ensure_blank_line(&mut out);
+                    out.push_str("## ");
+                }
+                ("p", false) | ("ul", _) => ensure_blank_line(&mut out),
+                ("h1" | "h2" | "h3" | "p", true) => out.push_str("\n\n"),
+                ("pre", false) => {
+                    ensure_blank_line(&mut out);
+                    out.push_str("```\n");
+                    in_pre = true;
+                }
+                ("pre", true) => {
+                    if !out.ends_with('\n') {
+                        out.push('\n');
+                    }
+                    out.push_str("```\n\n");
+                    in_pre = false;
+                }
+                ("code", false) if !in_pre => {
+                    // NOTE: puzzles emphasize answers as `<code><em>`, markdown can only emphasize the whole code span.
+                    code_em = rest.starts_with("<em>");
+                    out.push_str(if code_em { "*`" } else { "`" });
+                    in_code = true;
+                }
+                ("code", true) if !in_pre => {
+                    out.push_str(if code_em { "`*" } else { "`" });
+                    in_code = false;
+                    code_em = false;
+                }
+                ("em", _) if !in_pre && !in_code => out.push('*'),
+                ("a", false) if !in_pre => {
+                    out.push('[');
+                    links.push(href.unwrap_or_default());
+                }
+                ("a", true) if !in_pre => {
+                    let href = links.pop().unwrap_or_default();
+                    out.push_str(&format!("]({href})"));
+                }
+                ("li", false) => out.push_str("- "),
+                ("li", true) | ("br", _) => out.push('\n'),
+                _ => {}
+            }
+        } else {
+            let end = rest.find('<').unwrap_or(rest.len());
+            let text = &rest[..end];
+            rest = &rest[end..];
+
+            // NOTE: whitespace between block elements is formatting of the HTML source.
+            if in_pre || !text.trim().is_empty() {
+                out.push_str(&decode_entities(text));
+            }
+        }
+    }
+
+    normalize(&out)
+}
+
+/// Splits the inside of a tag into its lowercase name, whether it's a closing tag and its `href`.
+fn parse_tag(tag: &str) -> (String, bool, Option<String>) {
+    let (is_closing, tag) = match tag.strip_prefix('/') {
+        Some(tag) => (true, tag),
+        None => (false, tag),
+    };
+
+    let name = tag
+        .split(|c: char| c.is_whitespace() || c == '/')
+        .next()
+        .unwrap_or_default()
+        .to_lowercase();
+
+    let href = tag
+        .split_once("href=\"")
+        .and_then(|(_, rest)| rest.split_once('"').map(|(href, _)| decode_entities(href)));
+
+    (name, is_closing, href)
+}
+
+fn ensure_blank_line(out: &mut String) {
+    if out.is_empty() {
+        return;
+    }
+    while !out.ends_with("\n\n") {
+        out.push('\n');
+    }
+}
+
+fn decode_entities(text: &str) -> String {
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&apos;", "'")
+        .replace("&nbsp;", " ")
+        .replace("&amp;", "&")
+}
+
+/// Collapses runs of blank lines and ends the document with a single newline.
+fn normalize(markdown: &str) -> String {
+    let mut out = markdown.trim().to_string();
+    while out.contains("\n\n\n") {
+        out = out.replace("\n\n\n", "\n\n");
+    }
+    out.push('\n');
+    out
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::from_html;
+
+    #[test]
+    fn converts_headings_and_paragraphs() {
+        let html =
+            "<h2>--- Day 1: Test ---</h2>\n<p>Some <em>important</em> text.</p>\n<p>More text.</p>";
+        assert_eq!(
+            from_html(html),
+            "## --- Day 1: Test ---\n\nSome *important* text.\n\nMore text.\n"
+        );
+    }
+
+    #[test]
+    fn converts_code() {
+        let html = "<p>The answer is <code><em>42</em></code>.</p><pre><code>1 &lt; 2\n<em>3</em> &amp; 4\n</code></pre>";
+        assert_eq!(
+            from_html(html),
+            "The answer is *`42`*.\n\n```\n1 < 2\n3 & 4\n```\n"
+        );
+    }
+
+    #[test]
+    fn emphasizes_code() {
+        let html = "<p>A total of <code><em>11</em></code>, or <em><code>12</code></em>.</p>";
+        assert_eq!(from_html(html), "A total of *`11`*, or *`12`*.\n");
+    }
+
+    #[test]
+    fn converts_links_and_lists() {
+        let html = "<p>Get <a href=\"/2025/day/1/input\" target=\"_blank\">your input</a>:</p>\n<ul>\n<li>one</li>\n<li><code>two</code></li>\n</ul>";
+        assert_eq!(
+            from_html(html),
+            "Get [your input](/2025/day/1/input):\n\n- one\n- `two`\n"
+        );
+    }
+
+    #[test]
+    fn drops_unknown_tags() {
+        let html = "<p><span title=\"easter egg\">hidden</span> text</p>";
+        assert_eq!(from_html(html), "hidden text\n");
+    }
+}
@@ -1,68 +1,196 @@
-use std::{env, fs};
+use std::{env, fs, path::PathBuf};
 
+pub mod answer;
+pub mod answers;
 pub mod aoc_cli;
+pub mod bench;
+pub mod client;
 pub mod commands;
+pub mod compare;
+pub mod examples;
+pub mod input_sets;
+pub mod limits;
+pub mod panics;
+pub mod record;
+pub mod registry;
 pub mod runner;
+pub mod verify;
+pub mod watch;
 
 pub use day::*;
+pub use year::*;
 
 mod day;
+mod markdown;
+mod progress;
 mod readme_benchmarks;
 mod run_multi;
 mod timings;
+mod year;
 
 pub const ANSI_ITALIC: &str = "\x1b[3m";
 pub const ANSI_BOLD: &str = "\x1b[1m";
 pub const ANSI_RESET: &str = "\x1b[0m";
 
-/// Helper function that reads a text file to a string.
+/// Helper function that resolves the path of a file in a year's `data` folder. E.g. like `data/2025/inputs/01.txt`.
+///
+/// Files of the `AOC_YEAR` year that only exist in the layout without years, e.g. like
+/// `data/inputs/01.txt`, are resolved there.
+#[must_use]
+pub fn data_path(year: Year, folder: &str, file_name: &str) -> PathBuf {
+    let data = env::current_dir().unwrap().join("data");
+    let path = data.join(year.to_string()).join(folder).join(file_name);
+    let legacy_path = data.join(folder).join(file_name);
+
+    if !path.exists() && legacy_path.exists() && Year::from_env() == Some(year) {
+        return legacy_path;
+    }
+    path
+}
+
+/// Name of the binary that holds the solution of a day. E.g. like `2025-01`.
+#[must_use]
+pub fn bin_name(year: Year, day: Day) -> String {
+    format!("{year}-{day}")
+}
+
+/// Helper function that reads a text file of the `AOC_YEAR` year to a string.
 #[must_use]
 pub fn read_file(folder: &str, day: Day) -> String {
-    let cwd = env::current_dir().unwrap();
-    let filepath = cwd.join("data").join(folder).join(format!("{day}.txt"));
-    let f = fs::read_to_string(filepath);
-    f.expect("could not open input file")
+    read_year_file(folder, default_year(), day)
 }
 
-/// Helper function that reads a text file to string, appending a part suffix. E.g. like `01-2.txt`.
+/// Helper function that reads a text file of the `AOC_YEAR` year to string, appending a part
+/// suffix. E.g. like `01-2.txt`.
 #[must_use]
 pub fn read_file_part(folder: &str, day: Day, part: u8) -> String {
-    let cwd = env::current_dir().unwrap();
-    let filepath = cwd
-        .join("data")
-        .join(folder)
-        .join(format!("{day}-{part}.txt"));
-    let f = fs::read_to_string(filepath);
+    read_year_file_part(folder, default_year(), day, part)
+}
+
+/// Helper function that reads a text file of a year to a string.
+#[must_use]
+pub fn read_year_file(folder: &str, year: Year, day: Day) -> String {
+    let f = fs::read_to_string(data_path(year, folder, &format!("{day}.txt")));
+    f.expect("could not open input file")
+}
+
+/// Helper function that reads a text file of a year to string, appending a part suffix. E.g. like `01-2.txt`.
+#[must_use]
+pub fn read_year_file_part(folder: &str, year: Year, day: Day, part: u8) -> String {
+    let f = fs::read_to_string(data_path(year, folder, &format!("{day}-{part}.txt")));
     f.expect("could not open input file")
 }
 
-/// Creates the constant `DAY` and sets up the input and runner for each part.
+fn default_year() -> Year {
+    Year::from_env().expect("`AOC_YEAR` is not set to a valid year")
+}
+
+/// Creates the constants `YEAR` and `DAY` and sets up the input and runner for each part.
+///
+/// The optional, third parameter (1 or 2) allows you to only run a single part of the solution.
+///
+/// The year can be left out, e.g. like `solution!(1)` or `solution!(1, parse = parse)`, to take it
+/// from `AOC_YEAR` at compile time. A single part can only be selected along with the year, since
+/// `solution!(1, 2)` reads as day 2 of the year 1.
 ///
-/// The optional, second parameter (1 or 2) allows you to only run a single part of the solution.
+/// An optional `parse = <function>` parameter moves input parsing out of the parts: the function
+/// receives the input, is timed as its own phase, and both parts receive a reference to its output.
+///
+/// ```ignore
+/// advent_of_code::solution!(2025, 4, parse = parse);
+///
+/// pub fn parse(input: &str) -> Grid { Grid::parse_input(input) }
+/// pub fn part_one(grid: &Grid) -> Option<u64> { None }
+/// pub fn part_two(grid: &Grid) -> Option<u64> { None }
+/// ```
+///
+/// The parts are also exposed as `run_parts`, which the `all_days` binary collects into a
+/// [`Registry`](crate::template::registry::Registry) to run days in-process.
 #[macro_export]
 macro_rules! solution {
     ($day:expr) => {
-        $crate::solution!(@impl $day, [part_one, 1] [part_two, 2]);
+        $crate::solution!($crate::template::Year::parse_const(env!("AOC_YEAR")), $day);
+    };
+    ($day:expr, parse = $parse:expr) => {
+        $crate::solution!(
+            $crate::template::Year::parse_const(env!("AOC_YEAR")),
+            $day,
+            parse = $parse
+        );
+    };
+    ($year:expr, $day:expr) => {
+        $crate::solution!(@impl $year, $day, [part_one, 1] [part_two, 2]);
     };
-    ($day:expr, 1) => {
-        $crate::solution!(@impl $day, [part_one, 1]);
+    ($year:expr, $day:expr, 1) => {
+        $crate::solution!(@impl $year, $day, [part_one, 1]);
     };
-    ($day:expr, 2) => {
-        $crate::solution!(@impl $day, [part_two, 2]);
+    ($year:expr, $day:expr, 2) => {
+        $crate::solution!(@impl $year, $day, [part_two, 2]);
+    };
+    ($year:expr, $day:expr, parse = $parse:expr) => {
+        $crate::solution!(@impl $year, $day, @parse $parse, [part_one, 1] [part_two, 2]);
+    };
+    ($year:expr, $day:expr, 1, parse = $parse:expr) => {
+        $crate::solution!(@impl $year, $day, @parse $parse, [part_one, 1]);
+    };
+    ($year:expr, $day:expr, 2, parse = $parse:expr) => {
+        $crate::solution!(@impl $year, $day, @parse $parse, [part_two, 2]);
     };
 
-    (@impl $day:expr, $( [$func:expr, $part:expr] )*) => {
+    (@impl $year:expr, $day:expr, @parse $parse:expr, $( [$func:expr, $part:expr] )*) => {
+        $crate::solution!(@common $year, $day);
+
+        /// Parses `input` once and runs the enabled parts of the solution against it.
+        pub fn run_parts(
+            input: &str,
+            options: &$crate::template::runner::Options,
+        ) -> Vec<$crate::template::record::PartRecord> {
+            use $crate::template::runner::*;
+            let (parsed, parse_record) = run_parse($parse, input, DAY, options);
+            // NOTE: the parts can not run if parsing panicked.
+            let Some(parsed) = parsed else {
+                return vec![parse_record];
+            };
+            vec![parse_record, $( run_part_with($func, &parsed, DAY, $part, options), )*]
+        }
+    };
+    (@impl $year:expr, $day:expr, $( [$func:expr, $part:expr] )*) => {
+        $crate::solution!(@common $year, $day);
+
+        /// Runs the enabled parts of the solution against `input`.
+        pub fn run_parts(
+            input: &str,
+            options: &$crate::template::runner::Options,
+        ) -> Vec<$crate::template::record::PartRecord> {
+            use $crate::template::runner::*;
+            vec![$( run_part_with($func, input, DAY, $part, options), )*]
+        }
+    };
+
+    (@common $year:expr, $day:expr) => {
+        /// The year of the event.
+        pub const YEAR: $crate::template::Year = $crate::year!($year);
+
         /// The current day.
-        const DAY: $crate::template::Day = $crate::day!($day);
+        pub const DAY: $crate::template::Day = $crate::day!($day);
 
         #[cfg(feature = "dhat-heap")]
         #[global_allocator]
         static ALLOC: dhat::Alloc = dhat::Alloc;
 
+        #[allow(dead_code)]
         fn main() {
-            use $crate::template::runner::*;
-            let input = $crate::template::read_file("inputs", DAY);
-            $( run_part($func, &input, DAY, $part); )*
+            let options = $crate::template::runner::Options::from_args();
+            options.limits.apply();
+            if $crate::template::input_sets::is_requested() {
+                $crate::template::input_sets::run_all(YEAR, DAY, run_parts, &options);
+            } else {
+                let input = $crate::template::read_year_file("inputs", YEAR, DAY);
+                let records = run_parts(&input, &options);
+                if records.iter().any(|r| r.status.is_failure()) {
+                    std::process::exit(1);
+                }
+            }
         }
     };
 }
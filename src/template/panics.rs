@@ -0,0 +1,94 @@
+/// Catches panics of solution parts, so that a failing part does not abort the whole binary.
+///
+/// While a panic is caught, the default panic hook is silenced and the location of the panic is
+/// kept instead, to be reported next to the part. Set `RUST_BACKTRACE` to keep the default output.
+use std::{
+    any::Any,
+    cell::{Cell, RefCell},
+    env,
+    fmt::{self, Display},
+    panic::{self, AssertUnwindSafe},
+    sync::Once,
+};
+
+/// A caught panic.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Panic {
+    pub message: String,
+    /// Source location of the panic, e.g. `src/bin/2025-01.rs:7:42`.
+    pub location: Option<String>,
+}
+
+impl Display for Panic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.location {
+            Some(location) => write!(f, "panicked at {location}: {}", self.message),
+            None => write!(f, "panicked: {}", self.message),
+        }
+    }
+}
+
+thread_local! {
+    static IS_CATCHING: Cell<bool> = const { Cell::new(false) };
+    static LOCATION: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+static INSTALL_HOOK: Once = Once::new();
+
+/// Run `func`, returning its panic as an error instead of unwinding further.
+pub fn catch<T>(func: impl FnOnce() -> T) -> Result<T, Panic> {
+    INSTALL_HOOK.call_once(|| {
+        let default_hook = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            if IS_CATCHING.get() {
+                LOCATION.set(info.location().map(ToString::to_string));
+                if env::var_os("RUST_BACKTRACE").is_none() {
+                    return;
+                }
+            }
+            default_hook(info);
+        }));
+    });
+
+    let was_catching = IS_CATCHING.replace(true);
+    let result = panic::catch_unwind(AssertUnwindSafe(func));
+    IS_CATCHING.set(was_catching);
+
+    result.map_err(|payload| Panic {
+        message: message(payload.as_ref()),
+        location: LOCATION.take(),
+    })
+}
+
+fn message(payload: &(dyn Any + Send)) -> String {
+    payload
+        .downcast_ref::<&str>()
+        .map(ToString::to_string)
+        .or_else(|| payload.downcast_ref::<String>().cloned())
+        .unwrap_or_else(|| "Box<dyn Any>".into())
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::catch;
+
+    #[test]
+    fn returns_results() {
+        assert_eq!(catch(|| 42), Ok(42));
+    }
+
+    #[test]
+    fn catches_panics() {
+        let panic = catch(|| -> u8 { panic!("invalid input: {}", 7) }).unwrap_err();
+
+        assert_eq!(panic.message, "invalid input: 7");
+        assert!(
+            panic
+                .location
+                .unwrap()
+                .starts_with("src/template/panics.rs:")
+        );
+    }
+}
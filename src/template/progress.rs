@@ -0,0 +1,240 @@
+/// Stars of an event, worked out from the correct answers that are known locally.
+///
+/// A part earns a star once its correct answer is known, either from the answer ledger or from a
+/// puzzle description that was downloaded after solving it. Progress is printed as a calendar and
+/// stored in its own section of the readme, next to the benchmarks.
+use std::{fs, str::FromStr};
+
+use crate::template::answers::Ledger;
+use crate::template::readme_benchmarks::{Error, locate_table};
+use crate::template::{ANSI_BOLD, ANSI_RESET, Day, Year, all_days};
+
+static MARKER: &str = "<!--- progress table --->";
+
+/// Number of days per row of the calendar.
+const CALENDAR_WIDTH: usize = 5;
+
+/// Stars earned on each day of an event.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Progress {
+    pub year: Year,
+    /// Stars of every day of the event, in order.
+    pub stars: Vec<(Day, u8)>,
+}
+
+impl Progress {
+    /// Work out the stars of `year`, given whether the answer of a part is known.
+    pub fn from_answers(year: Year, is_known: impl Fn(Day, u8) -> bool) -> Self {
+        let mut stars: Vec<(Day, u8)> = all_days(year)
+            .map(|day| {
+                (
+                    day,
+                    [1, 2]
+                        .into_iter()
+                        .filter(|part| is_known(day, *part))
+                        .count() as u8,
+                )
+            })
+            .collect();
+
+        // NOTE: the second part of the last day has no answer, its star is earned by all others.
+        if let Some(((_, last), others)) = stars.split_last_mut()
+            && *last == 1
+            && others.iter().all(|(_, stars)| *stars == 2)
+        {
+            *last = 2;
+        }
+
+        Progress { year, stars }
+    }
+
+    /// Read the stars of `year` from the answer ledgers and puzzle descriptions.
+    pub fn read(year: Year) -> Result<Self, String> {
+        let mut ledgers = vec![];
+        for day in all_days(year) {
+            let ledger = Ledger::read_from_file(year, day)
+                .map_err(|e| format!("Could not read the answer ledger of day {day}: {e}"))?;
+            ledgers.push(ledger);
+        }
+
+        Ok(Progress::from_answers(year, |day, part| {
+            ledgers[usize::from(day.into_inner() - 1)]
+                .known_answer(year, day, part)
+                .is_some()
+        }))
+    }
+
+    pub fn total(&self) -> u32 {
+        self.stars.iter().map(|(_, stars)| u32::from(*stars)).sum()
+    }
+
+    pub fn max(&self) -> u32 {
+        self.stars.len() as u32 * 2
+    }
+
+    /// Render the event as a calendar of days, e.g. ` 3 ★☆`.
+    pub fn calendar(&self) -> String {
+        let mut lines = vec![
+            format!(
+                "{ANSI_BOLD}Advent of Code {}{ANSI_RESET}: {}/{} ★",
+                self.year,
+                self.total(),
+                self.max()
+            ),
+            String::new(),
+        ];
+
+        for row in self.stars.chunks(CALENDAR_WIDTH) {
+            let cells: Vec<String> = row
+                .iter()
+                .map(|(day, stars)| {
+                    let earned = "★".repeat(usize::from(*stars));
+                    let missing = "☆".repeat(usize::from(2 - *stars));
+                    format!("{:>2} {earned}{missing}", day.into_inner())
+                })
+                .collect();
+            lines.push(cells.join("   "));
+        }
+
+        lines.join("\n")
+    }
+}
+
+/// Years with a folder in `data`, most recent first.
+pub fn years_with_data() -> Vec<Year> {
+    let mut years: Vec<Year> = fs::read_dir("data")
+        .into_iter()
+        .flatten()
+        .flatten()
+        .filter_map(|entry| Year::from_str(entry.file_name().to_str()?).ok())
+        .collect();
+
+    years.sort_unstable_by(|a, b| b.cmp(a));
+    years
+}
+
+/* -------------------------------------------------------------------------- */
+
+fn construct_table(prefix: &str, progress: &[Progress]) -> String {
+    let header = format!("{prefix} Progress");
+
+    let mut lines: Vec<String> = vec![MARKER.into(), header];
+
+    // NOTE: events without a single star are left out.
+    for event in progress.iter().filter(|event| event.total() > 0) {
+        lines.extend([
+            String::new(),
+            format!("{prefix}# {}", event.year),
+            String::new(),
+            "| Day | Part 1 | Part 2 |".into(),
+            "| :---: | :---: | :---:  |".into(),
+        ]);
+
+        for (day, stars) in event.stars.iter().filter(|(_, stars)| *stars > 0) {
+            let cell = |part: u8| if *stars >= part { "⭐" } else { " " };
+            lines.push(format!(
+                "| [Day {}](https://adventofcode.com/{}/day/{}) | {} | {} |",
+                day.into_inner(),
+                event.year,
+                day.into_inner(),
+                cell(1),
+                cell(2),
+            ));
+        }
+
+        lines.push(String::new());
+        lines.push(format!("**Stars: {}/{}**", event.total(), event.max()));
+    }
+
+    lines.push(MARKER.into());
+
+    lines.join("\n")
+}
+
+fn update_content(s: &mut String, progress: &[Progress]) -> Result<(), Error> {
+    let positions = locate_table(s, MARKER)?;
+    let table = construct_table("##", progress);
+    s.replace_range(positions.pos_start..positions.pos_end, &table);
+    Ok(())
+}
+
+pub fn update_readme(progress: &[Progress]) -> Result<(), Error> {
+    let path = "README.md";
+    let mut readme = String::from_utf8_lossy(&fs::read(path)?).to_string();
+    update_content(&mut readme, progress)?;
+    fs::write(path, &readme)?;
+    Ok(())
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{MARKER, Progress, update_content};
+    use crate::{day, year};
+
+    fn progress() -> Progress {
+        Progress::from_answers(year!(2025), |day, part| {
+            day == day!(1) || (day == day!(3) && part == 1)
+        })
+    }
+
+    #[test]
+    fn counts_stars() {
+        let progress = progress();
+
+        assert_eq!(progress.stars.len(), 12);
+        assert_eq!(progress.stars[0], (day!(1), 2));
+        assert_eq!(progress.stars[2], (day!(3), 1));
+        assert_eq!(progress.total(), 3);
+        assert_eq!(progress.max(), 24);
+    }
+
+    #[test]
+    fn awards_last_star_for_all_others() {
+        let all_but_last =
+            Progress::from_answers(year!(2025), |day, part| day != day!(12) || part == 1);
+        let incomplete = Progress::from_answers(year!(2025), |day, part| {
+            day != day!(12) && day != day!(2) || part == 1
+        });
+
+        assert_eq!(all_but_last.total(), 24);
+        assert_eq!(incomplete.stars[11], (day!(12), 1));
+    }
+
+    #[test]
+    fn renders_calendar() {
+        let calendar = progress().calendar();
+        let lines: Vec<&str> = calendar.lines().collect();
+
+        assert!(lines[0].ends_with("3/24 ★"));
+        assert_eq!(lines[2], " 1 ★★    2 ☆☆    3 ★☆    4 ☆☆    5 ☆☆");
+        assert_eq!(lines[4], "11 ☆☆   12 ☆☆");
+    }
+
+    #[test]
+    fn updates_progress_table() {
+        let mut s = format!("foo\n{MARKER}\n{MARKER}\nbar");
+        update_content(&mut s, &[progress()]).unwrap();
+        update_content(&mut s, &[progress()]).unwrap();
+
+        let expected = [
+            "foo",
+            "<!--- progress table --->",
+            "## Progress",
+            "",
+            "### 2025",
+            "",
+            "| Day | Part 1 | Part 2 |",
+            "| :---: | :---: | :---:  |",
+            "| [Day 1](https://adventofcode.com/2025/day/1) | ⭐ | ⭐ |",
+            "| [Day 3](https://adventofcode.com/2025/day/3) | ⭐ |   |",
+            "",
+            "**Stars: 3/24**",
+            "<!--- progress table --->",
+            "bar",
+        ]
+        .join("\n");
+        assert_eq!(s, expected);
+    }
+}
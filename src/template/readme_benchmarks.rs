@@ -2,8 +2,8 @@
 /// The approach taken is similar to how `aoc-readme-stars` handles this.
 use std::{fs, io};
 
-use crate::template::Day;
-use crate::template::timings::Timings;
+use crate::template::timings::{StoredTimings, Timings};
+use crate::template::{Day, Year, bin_name};
 
 static MARKER: &str = "<!--- benchmarking table --->";
 
@@ -21,17 +21,18 @@ impl From<std::io::Error> for Error {
 }
 
 pub struct TablePosition {
-    pos_start: usize,
-    pos_end: usize,
+    pub(crate) pos_start: usize,
+    pub(crate) pos_end: usize,
 }
 
 #[must_use]
-pub fn get_path_for_bin(day: Day) -> String {
-    format!("./src/bin/{day}.rs")
+pub fn get_path_for_bin(year: Year, day: Day) -> String {
+    format!("./src/bin/{}.rs", bin_name(year, day))
 }
 
-fn locate_table(readme: &str) -> Result<TablePosition, Error> {
-    let matches: Vec<_> = readme.match_indices(MARKER).collect();
+/// Locate a section of the readme that is delimited by two occurrences of `marker`.
+pub(crate) fn locate_table(readme: &str, marker: &str) -> Result<TablePosition, Error> {
+    let matches: Vec<_> = readme.match_indices(marker).collect();
 
     if matches.len() > 2 {
         return Err(Error::Parser(
@@ -52,47 +53,72 @@ fn locate_table(readme: &str) -> Result<TablePosition, Error> {
     Ok(TablePosition { pos_start, pos_end })
 }
 
-fn construct_table(prefix: &str, timings: Timings, total_millis: f64) -> String {
+fn construct_table(prefix: &str, stored: StoredTimings) -> String {
     let header = format!("{prefix} Benchmarks");
 
-    let mut lines: Vec<String> = vec![
-        MARKER.into(),
-        header,
-        String::new(),
-        "| Day | Part 1 | Part 2 |".into(),
-        "| :---: | :---: | :---:  |".into(),
-    ];
-
-    for timing in timings.data {
-        let path = get_path_for_bin(timing.day);
-        lines.push(format!(
-            "| [Day {}]({}) | `{}` | `{}` |",
-            timing.day.into_inner(),
-            path,
-            timing.part_1.unwrap_or_else(|| "-".into()),
-            timing.part_2.unwrap_or_else(|| "-".into())
-        ));
+    let mut lines: Vec<String> = vec![MARKER.into(), header];
+
+    // NOTE: list the most recent event first.
+    for (year, timings) in stored.years.into_iter().rev() {
+        // NOTE: skip stored days that are not part of the event.
+        let timings = Timings {
+            data: timings
+                .data
+                .into_iter()
+                .filter(|t| year.has_day(t.day))
+                .collect(),
+        };
+        let total_millis = timings.total_millis();
+
+        lines.extend([
+            String::new(),
+            format!("{prefix}# {year}"),
+            String::new(),
+            "| Day | Parse | Part 1 | Part 2 |".into(),
+            "| :---: | :---: | :---: | :---:  |".into(),
+        ]);
+
+        for timing in timings.data {
+            let path = get_path_for_bin(year, timing.day);
+
+            // NOTE: the first part without a time is the one that was stopped by a limit.
+            let mut failure = timing.failure.map(|status| status.to_string());
+            let mut cell = |time: Option<String>| {
+                time.or_else(|| failure.take())
+                    .unwrap_or_else(|| "-".into())
+            };
+
+            let part_1 = cell(timing.part_1);
+            let part_2 = cell(timing.part_2);
+
+            lines.push(format!(
+                "| [Day {}]({}) | `{}` | `{part_1}` | `{part_2}` |",
+                timing.day.into_inner(),
+                path,
+                timing.parse.unwrap_or_else(|| "-".into()),
+            ));
+        }
+
+        lines.push(String::new());
+        lines.push(format!("**Total: {total_millis:.2}ms**"));
     }
 
-    lines.push(String::new());
-    lines.push(format!("**Total: {total_millis:.2}ms**"));
     lines.push(MARKER.into());
 
     lines.join("\n")
 }
 
-fn update_content(s: &mut String, timings: Timings, total_millis: f64) -> Result<(), Error> {
-    let positions = locate_table(s)?;
-    let table = construct_table("##", timings, total_millis);
+fn update_content(s: &mut String, stored: StoredTimings) -> Result<(), Error> {
+    let positions = locate_table(s, MARKER)?;
+    let table = construct_table("##", stored);
     s.replace_range(positions.pos_start..positions.pos_end, &table);
     Ok(())
 }
 
-pub fn update(timings: Timings) -> Result<(), Error> {
+pub fn update(stored: StoredTimings) -> Result<(), Error> {
     let path = "README.md";
     let mut readme = String::from_utf8_lossy(&fs::read(path)?).to_string();
-    let total_millis = timings.total_millis();
-    update_content(&mut readme, timings, total_millis)?;
+    update_content(&mut readme, stored)?;
     fs::write(path, &readme)?;
     Ok(())
 }
@@ -100,59 +126,81 @@ pub fn update(timings: Timings) -> Result<(), Error> {
 #[cfg(feature = "test_lib")]
 mod tests {
     use super::{MARKER, update_content};
-    use crate::{day, template::timings::Timing, template::timings::Timings};
+    use crate::{
+        day,
+        template::record::Status,
+        template::timings::{StoredTimings, Timing, Timings},
+        year,
+    };
 
-    fn get_mock_timings() -> Timings {
-        Timings {
+    fn get_mock_timings() -> StoredTimings {
+        let timings = Timings {
             data: vec![
                 Timing {
                     day: day!(1),
                     part_1: Some("10ms".into()),
                     part_2: Some("20ms".into()),
-                    total_nanos: 3e+10,
+                    total_nanos: 3e+7,
+                    part_1_stats: None,
+                    part_2_stats: None,
+                    parse: None,
+                    parse_stats: None,
+                    failure: None,
                 },
                 Timing {
                     day: day!(2),
                     part_1: Some("30ms".into()),
                     part_2: Some("40ms".into()),
-                    total_nanos: 7e+10,
+                    total_nanos: 7e+7,
+                    part_1_stats: None,
+                    part_2_stats: None,
+                    parse: Some("5ms".into()),
+                    parse_stats: None,
+                    failure: None,
                 },
                 Timing {
                     day: day!(4),
                     part_1: Some("40ms".into()),
                     part_2: Some("50ms".into()),
-                    total_nanos: 9e+10,
+                    total_nanos: 9e+7,
+                    part_1_stats: None,
+                    part_2_stats: None,
+                    parse: None,
+                    parse_stats: None,
+                    failure: None,
                 },
             ],
-        }
+        };
+
+        StoredTimings::default().merge(year!(2025), &timings)
     }
 
     #[test]
     #[should_panic]
     fn errors_if_marker_not_present() {
         let mut s = "# readme".to_string();
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, get_mock_timings()).unwrap();
     }
 
     #[test]
     #[should_panic]
     fn errors_if_too_many_markers_present() {
         let mut s = format!("{} {} {}", MARKER, MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, get_mock_timings()).unwrap();
     }
 
     #[test]
     fn updates_empty_benchmarks() {
         let mut s = format!("foo\nbar\n{}{}\nbaz", MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, get_mock_timings()).unwrap();
         assert_eq!(s.contains("## Benchmarks"), true);
     }
 
     #[test]
     fn updates_existing_benchmarks() {
         let mut s = format!("foo\nbar\n{}{}\nbaz", MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, get_mock_timings()).unwrap();
+        update_content(&mut s, get_mock_timings()).unwrap();
         assert_eq!(s.matches(MARKER).collect::<Vec<&str>>().len(), 2);
         assert_eq!(s.matches("## Benchmarks").collect::<Vec<&str>>().len(), 1);
     }
@@ -160,18 +208,20 @@ mod tests {
     #[test]
     fn format_benchmarks() {
         let mut s = format!("foo\nbar\n{}\n{}\nbaz", MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, get_mock_timings()).unwrap();
         let expected = [
             "foo",
             "bar",
             "<!--- benchmarking table --->",
             "## Benchmarks",
             "",
-            "| Day | Part 1 | Part 2 |",
-            "| :---: | :---: | :---:  |",
-            "| [Day 1](./src/bin/01.rs) | `10ms` | `20ms` |",
-            "| [Day 2](./src/bin/02.rs) | `30ms` | `40ms` |",
-            "| [Day 4](./src/bin/04.rs) | `40ms` | `50ms` |",
+            "### 2025",
+            "",
+            "| Day | Parse | Part 1 | Part 2 |",
+            "| :---: | :---: | :---: | :---:  |",
+            "| [Day 1](./src/bin/2025-01.rs) | `-` | `10ms` | `20ms` |",
+            "| [Day 2](./src/bin/2025-02.rs) | `5ms` | `30ms` | `40ms` |",
+            "| [Day 4](./src/bin/2025-04.rs) | `-` | `40ms` | `50ms` |",
             "",
             "**Total: 190.00ms**",
             "<!--- benchmarking table --->",
@@ -180,4 +230,43 @@ mod tests {
         .join("\n");
         assert_eq!(s, expected);
     }
+
+    #[test]
+    fn groups_benchmarks_by_year() {
+        let mut s = format!("{}{}", MARKER, MARKER);
+        let stored = get_mock_timings().merge(year!(2024), &get_mock_timings().get(year!(2025)));
+        update_content(&mut s, stored).unwrap();
+        let position = |needle: &str| s.find(needle).unwrap();
+        assert!(position("### 2025") < position("### 2024"));
+        assert!(position("### 2024") < position("./src/bin/2024-01.rs"));
+        assert_eq!(s.matches("**Total: 190.00ms**").count(), 2);
+    }
+
+    #[test]
+    fn skips_days_outside_of_event() {
+        let mut s = format!("{}{}", MARKER, MARKER);
+        let mut timings = get_mock_timings().get(year!(2025));
+        timings.data[2].day = day!(13);
+        update_content(
+            &mut s,
+            StoredTimings::default().merge(year!(2025), &timings),
+        )
+        .unwrap();
+        assert!(!s.contains("[Day 13]"));
+        assert!(s.contains("**Total: 100.00ms**"));
+    }
+
+    #[test]
+    fn marks_days_that_exceeded_limits() {
+        let mut s = format!("{}{}", MARKER, MARKER);
+        let mut timings = get_mock_timings().get(year!(2025));
+        timings.data[2].part_2 = None;
+        timings.data[2].failure = Some(Status::TimedOut);
+        update_content(
+            &mut s,
+            StoredTimings::default().merge(year!(2025), &timings),
+        )
+        .unwrap();
+        assert!(s.contains("| [Day 4](./src/bin/2025-04.rs) | `-` | `40ms` | `timed out` |"));
+    }
 }
@@ -0,0 +1,344 @@
+/// Machine-readable protocol between solution binaries and the multi-day runner.
+///
+/// When [`RECORDS_ENV`] is set, every part run by a solution binary emits one JSON line prefixed
+/// with [`RECORD_PREFIX`] next to its human-readable output.
+use std::{
+    collections::HashMap,
+    fmt::{self, Display},
+    str::FromStr,
+    time::Duration,
+};
+use tinyjson::JsonValue;
+
+use crate::template::Day;
+use crate::template::bench::BenchStats;
+
+/// Environment variable that enables emitting records from solution binaries.
+pub const RECORDS_ENV: &str = "AOC_RECORDS";
+
+/// Prefix that distinguishes record lines from regular solution output.
+pub const RECORD_PREFIX: &str = "@aoc-record ";
+
+/// Part number of the record emitted for the parse phase of a solution.
+pub const PARSE_PART: u8 = 0;
+
+/// Outcome of running a single part.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Status {
+    Solved,
+    Unsolved,
+    /// The solution was stopped by its timeout, see [`Limits`](crate::template::limits::Limits).
+    TimedOut,
+    /// The solution was stopped by its memory limit.
+    OutOfMemory,
+    /// The part returned an error, see [`PartRecord::error`].
+    Failed,
+    /// The part panicked, see [`PartRecord::error`].
+    Panicked,
+    /// The solution process exited unsuccessfully without reporting the part.
+    Crashed,
+}
+
+impl Status {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Status::Solved => "solved",
+            Status::Unsolved => "unsolved",
+            Status::TimedOut => "timed_out",
+            Status::OutOfMemory => "out_of_memory",
+            Status::Failed => "failed",
+            Status::Panicked => "panicked",
+            Status::Crashed => "crashed",
+        }
+    }
+
+    /// Returns `true` if the part failed, as opposed to being solved or returning no answer.
+    pub fn is_failure(self) -> bool {
+        !matches!(self, Status::Solved | Status::Unsolved)
+    }
+}
+
+impl Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Status::TimedOut => "timed out",
+            Status::OutOfMemory => "out of memory",
+            status => status.as_str(),
+        })
+    }
+}
+
+impl FromStr for Status {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "solved" => Ok(Status::Solved),
+            "unsolved" => Ok(Status::Unsolved),
+            "timed_out" => Ok(Status::TimedOut),
+            "out_of_memory" => Ok(Status::OutOfMemory),
+            "failed" => Ok(Status::Failed),
+            "panicked" => Ok(Status::Panicked),
+            "crashed" => Ok(Status::Crashed),
+            _ => Err(format!("unknown record status `{s}`.")),
+        }
+    }
+}
+
+/// Result of running a single part of a solution.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PartRecord {
+    pub day: Day,
+    /// Part number, or [`PARSE_PART`] for the parse phase.
+    pub part: u8,
+    pub answer: Option<String>,
+    /// Time per run. This is the median of all samples when benched.
+    pub duration: Duration,
+    pub samples: u128,
+    /// Statistics of the samples, present when the part was benched.
+    pub stats: Option<BenchStats>,
+    pub status: Status,
+    /// Description of the failure of a part, e.g. its panic message.
+    pub error: Option<String>,
+}
+
+impl PartRecord {
+    /// Record of a part that failed before reporting a result.
+    pub fn failed(day: Day, part: u8, status: Status, error: Option<String>) -> Self {
+        PartRecord {
+            day,
+            part,
+            answer: None,
+            duration: Duration::ZERO,
+            samples: 0,
+            stats: None,
+            status,
+            error,
+        }
+    }
+
+    /// Record of a part that returned `answer`, or no result if it is [`None`]. Used in tests.
+    #[cfg(feature = "test_lib")]
+    pub fn answered(day: Day, part: u8, answer: Option<&str>) -> Self {
+        PartRecord {
+            answer: answer.map(ToString::to_string),
+            samples: 1,
+            ..PartRecord::failed(
+                day,
+                part,
+                if answer.is_some() {
+                    Status::Solved
+                } else {
+                    Status::Unsolved
+                },
+                None,
+            )
+        }
+    }
+
+    /// Serializes the record to a single, prefixed output line.
+    pub fn to_line(&self) -> String {
+        format!(
+            "{RECORD_PREFIX}{}",
+            JsonValue::from(self).stringify().unwrap()
+        )
+    }
+
+    /// Parses a line of solution output. Returns `None` if the line is not a record.
+    pub fn from_line(line: &str) -> Option<Result<Self, String>> {
+        let json = line.strip_prefix(RECORD_PREFIX)?;
+        Some(
+            JsonValue::from_str(json)
+                .map_err(|_| "record is not valid JSON.".to_string())
+                .and_then(|value| PartRecord::try_from(&value)),
+        )
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&PartRecord> for JsonValue {
+    #[allow(clippy::cast_precision_loss)]
+    fn from(value: &PartRecord) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert("day".into(), JsonValue::String(value.day.to_string()));
+        map.insert("part".into(), JsonValue::Number(f64::from(value.part)));
+        map.insert(
+            "answer".into(),
+            match &value.answer {
+                Some(x) => JsonValue::String(x.clone()),
+                None => JsonValue::Null,
+            },
+        );
+        map.insert(
+            "nanos".into(),
+            JsonValue::Number(value.duration.as_nanos() as f64),
+        );
+        map.insert("samples".into(), JsonValue::Number(value.samples as f64));
+        map.insert(
+            "stats".into(),
+            match &value.stats {
+                Some(x) => JsonValue::from(x),
+                None => JsonValue::Null,
+            },
+        );
+        map.insert(
+            "status".into(),
+            JsonValue::String(value.status.as_str().into()),
+        );
+        if let Some(error) = &value.error {
+            map.insert("error".into(), JsonValue::String(error.clone()));
+        }
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for PartRecord {
+    type Error = String;
+
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected record to be a JSON object.")?;
+
+        let day = json
+            .get("day")
+            .and_then(|v| v.get::<String>())
+            .and_then(|day| Day::from_str(day).ok())
+            .ok_or("Expected record.day to be a Day struct.")?;
+
+        let part = json
+            .get("part")
+            .and_then(|v| v.get::<f64>())
+            .map(|v| *v as u8)
+            .ok_or("Expected record.part to be a number.")?;
+
+        let answer = json
+            .get("answer")
+            .map(|v| if v.is_null() { None } else { v.get::<String>() })
+            .ok_or("Expected record.answer to be null or string.")?;
+
+        let nanos = json
+            .get("nanos")
+            .and_then(|v| v.get::<f64>())
+            .ok_or("Expected record.nanos to be a number.")?;
+
+        let samples = json
+            .get("samples")
+            .and_then(|v| v.get::<f64>())
+            .ok_or("Expected record.samples to be a number.")?;
+
+        let stats = match json.get("stats") {
+            Some(v) if !v.is_null() => Some(BenchStats::try_from(v)?),
+            _ => None,
+        };
+
+        let status = json
+            .get("status")
+            .and_then(|v| v.get::<String>())
+            .ok_or("Expected record.status to be a string.")?
+            .parse()?;
+
+        let error = json.get("error").and_then(|v| v.get::<String>()).cloned();
+
+        Ok(PartRecord {
+            day,
+            part,
+            answer: answer.cloned(),
+            duration: Duration::from_nanos(*nanos as u64),
+            samples: *samples as u128,
+            stats,
+            status,
+            error,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::time::Duration;
+
+    use super::{PartRecord, Status};
+    use crate::{day, template::bench::BenchStats};
+
+    fn get_mock_record() -> PartRecord {
+        PartRecord {
+            day: day!(3),
+            part: 2,
+            answer: Some("1 (2) @ 3 samples)".into()),
+            duration: Duration::from_nanos(74_130),
+            samples: 100_000,
+            stats: BenchStats::from_samples(&[
+                Duration::from_nanos(74_000),
+                Duration::from_nanos(74_130),
+                Duration::from_nanos(75_000),
+            ]),
+            status: Status::Solved,
+            error: None,
+        }
+    }
+
+    #[test]
+    fn round_trips_records() {
+        let record = get_mock_record();
+        let parsed = PartRecord::from_line(&record.to_line()).unwrap().unwrap();
+        assert_eq!(parsed, record);
+    }
+
+    #[test]
+    fn round_trips_multiline_answers() {
+        let record = PartRecord {
+            answer: Some("#..#\n####\n#..#".into()),
+            ..get_mock_record()
+        };
+        let line = record.to_line();
+        assert!(!line.contains('\n'));
+        assert_eq!(PartRecord::from_line(&line).unwrap().unwrap(), record);
+    }
+
+    #[test]
+    fn round_trips_unsolved_parts() {
+        let record = PartRecord {
+            answer: None,
+            duration: Duration::ZERO,
+            samples: 1,
+            stats: None,
+            status: Status::Unsolved,
+            ..get_mock_record()
+        };
+        assert_eq!(
+            PartRecord::from_line(&record.to_line()).unwrap().unwrap(),
+            record
+        );
+    }
+
+    #[test]
+    fn round_trips_failed_parts() {
+        let record = PartRecord::failed(
+            day!(3),
+            1,
+            Status::Panicked,
+            Some("panicked at src/bin/2025-03.rs:7:42: explicit panic".into()),
+        );
+        assert_eq!(
+            PartRecord::from_line(&record.to_line()).unwrap().unwrap(),
+            record
+        );
+    }
+
+    #[test]
+    fn ignores_regular_output() {
+        assert!(PartRecord::from_line("Part 1: 0 (74.13ns @ 100000 samples)").is_none());
+    }
+
+    #[test]
+    fn errors_for_malformed_records() {
+        let line = format!("{}{}", super::RECORD_PREFIX, r#"{ "day": "03" }"#);
+        assert!(PartRecord::from_line(&line).unwrap().is_err());
+    }
+}
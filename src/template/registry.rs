@@ -0,0 +1,90 @@
+/// In-process registry of solutions.
+/// Allows a single binary to run any subset of days without spawning `cargo` for each of them.
+use std::{collections::HashSet, fs};
+
+use crate::template::record::PartRecord;
+use crate::template::runner::Options;
+use crate::template::{ANSI_BOLD, ANSI_RESET, Day, Year, all_days, data_path};
+
+/// Entry point of a single day, as exposed by the [`solution`](crate::solution) macro.
+#[derive(Clone, Copy, Debug)]
+pub struct Solution {
+    pub year: Year,
+    pub day: Day,
+    pub run: fn(&str, &Options) -> Vec<PartRecord>,
+}
+
+impl Solution {
+    /// Run all parts of the solution against the day's puzzle input.
+    pub fn run(&self, options: &Options) -> Vec<PartRecord> {
+        let path = data_path(self.year, "inputs", &format!("{}.txt", self.day));
+        match fs::read_to_string(&path) {
+            Ok(input) => (self.run)(&input, options),
+            Err(e) => {
+                eprintln!("Could not read input file {}: {e}", path.display());
+                vec![]
+            }
+        }
+    }
+}
+
+/// Set of solutions that are compiled into the current binary.
+#[derive(Clone, Debug, Default)]
+pub struct Registry {
+    solutions: Vec<Solution>,
+}
+
+impl Registry {
+    pub fn new(mut solutions: Vec<Solution>) -> Self {
+        solutions.sort_unstable_by_key(|s| (s.year, s.day));
+        Self { solutions }
+    }
+
+    pub fn get(&self, year: Year, day: Day) -> Option<&Solution> {
+        self.solutions
+            .iter()
+            .find(|s| s.year == year && s.day == day)
+    }
+
+    /// Days of `year` that have a solution.
+    pub fn days(&self, year: Year) -> impl Iterator<Item = Day> + '_ {
+        self.solutions
+            .iter()
+            .filter(move |s| s.year == year)
+            .map(|s| s.day)
+    }
+
+    /// Run the given days of `year` in order, printing the same output as `run_multi`.
+    pub fn run_days(
+        &self,
+        year: Year,
+        days_to_run: &HashSet<Day>,
+        options: &Options,
+    ) -> Vec<PartRecord> {
+        let mut records = vec![];
+        let mut need_space = false;
+
+        for day in all_days(year).filter(|day| days_to_run.contains(day)) {
+            if need_space {
+                println!();
+            }
+            need_space = true;
+
+            println!("{ANSI_BOLD}Day {day}{ANSI_RESET}");
+            println!("------");
+
+            let day_records = self
+                .get(year, day)
+                .map(|solution| solution.run(options))
+                .unwrap_or_default();
+
+            if day_records.is_empty() {
+                println!("Not solved.");
+            }
+
+            records.extend(day_records);
+        }
+
+        records
+    }
+}
@@ -1,48 +1,274 @@
-use std::{collections::HashSet, io};
+use std::{
+    collections::{BTreeMap, HashSet},
+    fmt::{self, Display},
+    io, process,
+    sync::{Mutex, mpsc},
+    thread,
+};
 
-use crate::template::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET, Day};
+use crate::template::record::{PARSE_PART, PartRecord, Status};
+use crate::template::runner::Options;
+use crate::template::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET, Day, Year, bin_name};
 
 use super::{
     all_days,
     timings::{Timing, Timings},
 };
 
-pub fn run_multi(days_to_run: &HashSet<Day>, is_release: bool, is_timed: bool) -> Option<Timings> {
-    let mut timings: Vec<Timing> = Vec::with_capacity(days_to_run.len());
+/// Outcome of running a set of days.
+pub struct Summary {
+    /// Timings of the days that ran, present if the days were timed.
+    pub timings: Option<Timings>,
+    /// Days that failed, e.g. because a part panicked or exceeded its time limit.
+    pub failed: Vec<Day>,
+}
 
-    let mut need_space = false;
+impl Summary {
+    pub fn has_failures(&self) -> bool {
+        !self.failed.is_empty()
+    }
+}
 
-    // NOTE: use non-duplicate, sorted day values.
-    all_days()
-        .filter(|day| days_to_run.contains(day))
-        .for_each(|day| {
-            if need_space {
-                println!();
+pub fn run_multi(
+    year: Year,
+    days_to_run: &HashSet<Day>,
+    is_release: bool,
+    options: &Options,
+    in_process: bool,
+    jobs: Option<usize>,
+) -> Summary {
+    let mut results = Results::default();
+
+    if in_process {
+        if !options.limits.is_empty() {
+            eprintln!("Time and memory limits are not enforced with `--in-process`.");
+        }
+
+        let records = match child_commands::run_in_process(year, days_to_run, options, is_release) {
+            Ok(records) => records,
+            Err(e) => {
+                eprintln!("Could not run the `all_days` binary: {e}");
+                process::exit(1);
             }
-            need_space = true;
+        };
 
-            println!("{ANSI_BOLD}Day {day}{ANSI_RESET}");
-            println!("------");
+        // NOTE: the `all_days` binary prints day headers itself.
+        all_days(year)
+            .filter(|day| days_to_run.contains(day))
+            .for_each(|day| {
+                let day_records: Vec<PartRecord> =
+                    records.iter().filter(|r| r.day == day).cloned().collect();
+                results.add(day, &day_records);
+            });
+    } else if let Some(jobs) = jobs {
+        run_parallel(year, days_to_run, is_release, options, jobs, &mut results);
+    } else {
+        run_sequential(year, days_to_run, is_release, options, &mut results);
+    }
 
-            let output = child_commands::run_solution(day, is_timed, is_release).unwrap();
+    results.print_summary();
 
-            if output.is_empty() {
-                println!("Not solved.");
-            } else {
-                let val = child_commands::parse_exec_time(&output, day);
-                timings.push(val);
-            }
-        });
+    let failed = results.failures.iter().map(|(day, _)| *day).collect();
 
-    if is_timed {
-        let timings = Timings { data: timings };
+    let timings = options.timed.then(|| {
+        let timings = Timings {
+            data: results.timings,
+        };
         let total_millis = timings.total_millis();
         println!(
             "\n{ANSI_BOLD}Total (Run):{ANSI_RESET} {ANSI_ITALIC}{total_millis:.2}ms{ANSI_RESET}"
         );
-        Some(timings)
-    } else {
-        None
+        timings
+    });
+
+    Summary { timings, failed }
+}
+
+/// Run each day in its own solution binary.
+fn run_sequential(
+    year: Year,
+    days_to_run: &HashSet<Day>,
+    is_release: bool,
+    options: &Options,
+    results: &mut Results,
+) {
+    // NOTE: use non-duplicate, sorted day values.
+    all_days(year)
+        .filter(|day| days_to_run.contains(day))
+        .enumerate()
+        .for_each(|(index, day)| {
+            print_day_header(day, index == 0);
+
+            let records = child_commands::run_solution(year, day, options, is_release)
+                .unwrap_or_else(|e| vec![not_run(day, &e)]);
+            print_not_solved(&records);
+            results.add(day, &records);
+        });
+}
+
+/// Build the solution binaries once, then run up to `jobs` days at the same time.
+/// The output of each day is buffered and printed in day order.
+fn run_parallel(
+    year: Year,
+    days_to_run: &HashSet<Day>,
+    is_release: bool,
+    options: &Options,
+    jobs: usize,
+    results: &mut Results,
+) {
+    let days: Vec<Day> = all_days(year)
+        .filter(|day| days_to_run.contains(day))
+        .collect();
+
+    let executables = match child_commands::build_solutions(year, &days, is_release) {
+        Ok(executables) => executables,
+        Err(e) => {
+            eprintln!("Failed to build solutions: {e}");
+            process::exit(1);
+        }
+    };
+
+    let mut printed = 0;
+    run_pool(
+        &days,
+        jobs,
+        |day| {
+            // NOTE: days without a solution binary have no output.
+            executables.get(&day).map(|executable| {
+                child_commands::run_executable(executable, day, options)
+                    .unwrap_or_else(|e| (vec![not_run(day, &e)], vec![]))
+            })
+        },
+        |day, output| {
+            print_day_header(day, printed == 0);
+            printed += 1;
+
+            let (records, lines) = output.unwrap_or_default();
+            lines.iter().for_each(child_commands::Line::print);
+            print_not_solved(&records);
+            results.add(day, &records);
+        },
+    );
+}
+
+/// Run `work` for every day on up to `jobs` threads, and pass the outputs to `finish` in day order.
+/// `finish` is called as soon as a day and all days before it are done.
+fn run_pool<O: Send>(
+    days: &[Day],
+    jobs: usize,
+    work: impl Fn(Day) -> O + Sync,
+    mut finish: impl FnMut(Day, O),
+) {
+    let queue = Mutex::new(days.iter().copied());
+    let (sender, receiver) = mpsc::channel();
+
+    thread::scope(|scope| {
+        for _ in 0..jobs.min(days.len()) {
+            let (queue, work, sender) = (&queue, &work, sender.clone());
+
+            scope.spawn(move || {
+                loop {
+                    // NOTE: the lock is released before the day runs, so that workers run in parallel.
+                    let next = queue.lock().unwrap().next();
+                    let Some(day) = next else {
+                        break;
+                    };
+                    sender.send((day, work(day))).unwrap();
+                }
+            });
+        }
+        drop(sender);
+
+        let mut finished = BTreeMap::new();
+        let mut next = 0;
+
+        for (day, output) in receiver {
+            finished.insert(day, output);
+
+            // NOTE: finish every day that is done and has no unfinished day before it.
+            while let Some(output) = days.get(next).and_then(|day| finished.remove(day)) {
+                finish(days[next], output);
+                next += 1;
+            }
+        }
+    });
+}
+
+fn print_day_header(day: Day, is_first: bool) {
+    if !is_first {
+        println!();
+    }
+
+    println!("{ANSI_BOLD}Day {day}{ANSI_RESET}");
+    println!("------");
+}
+
+fn print_not_solved(records: &[PartRecord]) {
+    if records.is_empty() {
+        println!("Not solved.");
+    }
+}
+
+/// Record of a day whose solution could not be started.
+fn not_run(day: Day, error: &Error) -> PartRecord {
+    PartRecord::failed(
+        day,
+        PARSE_PART,
+        Status::Crashed,
+        Some(format!("could not run the solution: {error}")),
+    )
+}
+
+/// Timings and failures of the days that ran so far.
+#[derive(Default)]
+struct Results {
+    timings: Vec<Timing>,
+    failures: Vec<(Day, String)>,
+    not_solved: usize,
+}
+
+impl Results {
+    fn add(&mut self, day: Day, records: &[PartRecord]) {
+        if records.is_empty() {
+            self.not_solved += 1;
+            return;
+        }
+
+        if let Some(failure) = records.iter().find(|r| r.status.is_failure()) {
+            self.failures.push((day, describe_failure(failure)));
+        }
+
+        self.timings.push(Timing::from_records(day, records));
+    }
+
+    fn print_summary(&self) {
+        let completed = self.timings.len() - self.failures.len();
+
+        println!("\n{ANSI_BOLD}Summary{ANSI_RESET}");
+        println!("------");
+        println!(
+            "{completed} completed, {} failed, {} not solved",
+            self.failures.len(),
+            self.not_solved
+        );
+
+        for (day, failure) in &self.failures {
+            println!("Day {day}: {failure}");
+        }
+    }
+}
+
+/// Describe the failed part of a day, e.g. `Part 2: timed out`.
+fn describe_failure(record: &PartRecord) -> String {
+    let error = record
+        .error
+        .clone()
+        .unwrap_or_else(|| record.status.to_string());
+
+    match (record.status, record.part) {
+        (Status::Crashed, _) => error,
+        (_, PARSE_PART) => format!("Parse: {error}"),
+        (_, part) => format!("Part {part}: {error}"),
     }
 }
 
@@ -50,9 +276,20 @@ pub fn run_multi(days_to_run: &HashSet<Day>, is_release: bool, is_timed: bool) -
 #[derive(Debug)]
 pub enum Error {
     BrokenPipe,
+    BuildFailed,
     IO(io::Error),
 }
 
+impl Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::BrokenPipe => write!(f, "broken pipe"),
+            Error::BuildFailed => write!(f, "build failed"),
+            Error::IO(e) => write!(f, "{e}"),
+        }
+    }
+}
+
 impl From<std::io::Error> for Error {
     fn from(e: std::io::Error) -> Self {
         Error::IO(e)
@@ -60,198 +297,334 @@ impl From<std::io::Error> for Error {
 }
 
 #[must_use]
-pub fn get_path_for_bin(day: Day) -> String {
-    format!("./src/bin/{day}.rs")
+pub fn get_path_for_bin(year: Year, day: Day) -> String {
+    format!("./src/bin/{}.rs", bin_name(year, day))
 }
 
 /// All solutions live in isolated binaries.
-/// This module encapsulates interaction with these binaries, both invoking them as well as parsing the timing output.
+/// This module encapsulates interaction with these binaries, both invoking them as well as collecting their result records.
 pub mod child_commands {
     use super::{Error, get_path_for_bin};
-    use crate::template::Day;
+    use crate::template::limits;
+    use crate::template::record::{PartRecord, RECORDS_ENV, Status};
+    use crate::template::runner::Options;
+    use crate::template::{Day, Year, bin_name};
     use std::{
+        collections::{HashMap, HashSet},
         io::{BufRead, BufReader},
-        path::Path,
-        process::{Command, Stdio},
+        mem,
+        path::{Path, PathBuf},
+        process::{Command, ExitStatus, Stdio},
+        str::FromStr,
+        sync::{Arc, Mutex},
         thread,
     };
+    use tinyjson::JsonValue;
 
     /// Run the solution bin for a given day
-    pub fn run_solution(day: Day, is_timed: bool, is_release: bool) -> Result<Vec<String>, Error> {
+    pub fn run_solution(
+        year: Year,
+        day: Day,
+        options: &Options,
+        is_release: bool,
+    ) -> Result<Vec<PartRecord>, Error> {
         // skip command invocation for days that have not been scaffolded yet.
-        if !Path::new(&get_path_for_bin(day)).exists() {
+        if !Path::new(&get_path_for_bin(year, day)).exists() {
             return Ok(vec![]);
         }
 
-        let day_padded = day.to_string();
-        let mut args = vec!["run", "--quiet", "--bin", &day_padded];
+        let mut args: Vec<String> = vec![
+            "run".into(),
+            "--quiet".into(),
+            "--bin".into(),
+            bin_name(year, day),
+        ];
 
         if is_release {
-            args.push("--release");
+            args.push("--release".into());
         }
 
-        if is_timed {
-            // mirror `--time` flag to child invocations.
-            args.push("--");
-            args.push("--time");
+        // mirror `--time`, bench and limit flags to child invocations.
+        args.push("--".into());
+        args.extend(options.to_args());
+
+        Ok(run_command(Command::new("cargo").args(&args), false)?.into_records(day))
+    }
+
+    /// Build the solution bins of `days` with a single `cargo build`.
+    /// Returns the executable of every day that has been scaffolded.
+    pub fn build_solutions(
+        year: Year,
+        days: &[Day],
+        is_release: bool,
+    ) -> Result<HashMap<Day, PathBuf>, Error> {
+        let days: Vec<Day> = days
+            .iter()
+            .copied()
+            .filter(|day| Path::new(&get_path_for_bin(year, *day)).exists())
+            .collect();
+
+        if days.is_empty() {
+            return Ok(HashMap::new());
         }
 
-        // spawn child command with piped stdout/stderr.
-        // forward output to stdout/stderr while grabbing stdout lines.
+        let mut args: Vec<String> = vec![
+            "build".into(),
+            "--quiet".into(),
+            "--message-format=json-render-diagnostics".into(),
+        ];
+
+        for day in &days {
+            args.extend(["--bin".into(), bin_name(year, *day)]);
+        }
 
-        let mut cmd = Command::new("cargo")
+        if is_release {
+            args.push("--release".into());
+        }
+
+        let output = Command::new("cargo")
             .args(&args)
-            .stdout(Stdio::piped())
-            .stderr(Stdio::piped())
-            .spawn()?;
+            .stderr(Stdio::inherit())
+            .output()?;
 
-        let stdout = BufReader::new(cmd.stdout.take().ok_or(super::Error::BrokenPipe)?);
-        let stderr = BufReader::new(cmd.stderr.take().ok_or(super::Error::BrokenPipe)?);
+        if !output.status.success() {
+            return Err(Error::BuildFailed);
+        }
 
-        let mut output = vec![];
+        // NOTE: cargo reports the path of each built binary, which depends on the target directory.
+        let messages = String::from_utf8_lossy(&output.stdout);
+        Ok(days
+            .into_iter()
+            .filter_map(|day| {
+                let name = bin_name(year, day);
+                let executable = messages.lines().find_map(|line| executable(line, &name))?;
+                Some((day, executable))
+            })
+            .collect())
+    }
 
-        let thread = thread::spawn(move || {
-            stderr.lines().for_each(|line| {
-                eprintln!("{}", line.unwrap());
-            });
-        });
+    /// Path of the executable in a `compiler-artifact` message of `cargo build`, if it was built for `bin`.
+    fn executable(message: &str, bin: &str) -> Option<PathBuf> {
+        let json = JsonValue::from_str(message).ok()?;
+        let json = json.get::<HashMap<String, JsonValue>>()?;
 
-        for line in stdout.lines() {
-            let line = line.unwrap();
-            println!("{line}");
-            output.push(line);
+        let name = json
+            .get("target")?
+            .get::<HashMap<String, JsonValue>>()?
+            .get("name")?
+            .get::<String>()?;
+
+        if name != bin {
+            return None;
         }
 
-        thread.join().unwrap();
-        cmd.wait()?;
+        json.get("executable")?.get::<String>().map(PathBuf::from)
+    }
 
-        Ok(output)
+    /// Run a solution binary that was built by [`build_solutions`].
+    /// Its output is buffered and returned alongside its result records instead of being forwarded.
+    pub fn run_executable(
+        executable: &Path,
+        day: Day,
+        options: &Options,
+    ) -> Result<(Vec<PartRecord>, Vec<Line>), Error> {
+        let mut output = run_command(Command::new(executable).args(options.to_args()), true)?;
+        let lines = mem::take(&mut output.lines);
+        Ok((output.into_records(day), lines))
     }
 
-    pub fn parse_exec_time(output: &[String], day: Day) -> super::Timing {
-        let mut timings = super::Timing {
-            day,
-            part_1: None,
-            part_2: None,
-            total_nanos: 0_f64,
-        };
+    /// Run a set of days in-process with the `all_days` binary.
+    pub fn run_in_process(
+        year: Year,
+        days_to_run: &HashSet<Day>,
+        options: &Options,
+        is_release: bool,
+    ) -> Result<Vec<PartRecord>, Error> {
+        // `all_days` runs every registered day when invoked without days.
+        if days_to_run.is_empty() {
+            return Ok(vec![]);
+        }
 
-        output
-            .iter()
-            .filter_map(|l| {
-                if !l.contains(" samples)") {
-                    return None;
-                }
+        let mut args: Vec<String> = vec![
+            "run".into(),
+            "--quiet".into(),
+            "--bin".into(),
+            "all_days".into(),
+        ];
 
-                let Some((timing_str, nanos)) = parse_time(l) else {
-                    eprintln!("Could not parse timings from line: {l}");
-                    return None;
-                };
+        if is_release {
+            args.push("--release".into());
+        }
 
-                let part = l.split(':').next()?;
-                Some((part, timing_str, nanos))
-            })
-            .for_each(|(part, timing_str, nanos)| {
-                if part.contains("Part 1") {
-                    timings.part_1 = Some(timing_str.into());
-                } else if part.contains("Part 2") {
-                    timings.part_2 = Some(timing_str.into());
-                }
+        args.push("--".into());
+        args.extend(["--year".into(), year.to_string()]);
+        args.extend(options.to_args());
+        args.extend(days_to_run.iter().map(Day::to_string));
 
-                timings.total_nanos += nanos;
-            });
+        let output = run_command(Command::new("cargo").args(&args), false)?;
 
-        timings
+        // NOTE: days run in order, so a crash happened in the last day that reported a part.
+        let last_day = output
+            .records
+            .last()
+            .map(|r| r.day)
+            .or_else(|| days_to_run.iter().min().copied());
+
+        Ok(match last_day {
+            Some(day) => output.into_records(day),
+            None => output.records,
+        })
     }
 
-    fn parse_to_float(s: &str, postfix: &str) -> Option<f64> {
-        s.split(postfix).next()?.parse().ok()
+    /// A line of output of a solution binary.
+    #[derive(Clone, Debug)]
+    pub enum Line {
+        Stdout(String),
+        Stderr(String),
     }
 
-    fn parse_time(line: &str) -> Option<(&str, f64)> {
-        // for possible time formats, see: https://github.com/rust-lang/rust/blob/1.64.0/library/core/src/time.rs#L1176-L1200
-        let str_timing = line
-            .split(" samples)")
-            .next()?
-            .split('(')
-            .next_back()?
-            .split('@')
-            .next()?
-            .trim();
-
-        let parsed_timing = match str_timing {
-            s if s.contains("ns") => s.split("ns").next()?.parse::<f64>().ok(),
-            s if s.contains("µs") => parse_to_float(s, "µs").map(|x| x * 1000_f64),
-            s if s.contains("ms") => parse_to_float(s, "ms").map(|x| x * 1_000_000_f64),
-            s => parse_to_float(s, "s").map(|x| x * 1_000_000_000_f64),
-        }?;
-
-        Some((str_timing, parsed_timing))
+    impl Line {
+        pub fn print(&self) {
+            match self {
+                Line::Stdout(line) => println!("{line}"),
+                Line::Stderr(line) => eprintln!("{line}"),
+            }
+        }
     }
 
-    /// copied from: https://github.com/rust-lang/rust/blob/1.64.0/library/std/src/macros.rs#L328-L333
-    #[cfg(feature = "test_lib")]
-    macro_rules! assert_approx_eq {
-        ($a:expr, $b:expr) => {{
-            let (a, b) = (&$a, &$b);
-            assert!(
-                (*a - *b).abs() < 1.0e-6,
-                "{} is not approximately equal to {}",
-                *a,
-                *b
-            );
-        }};
+    /// Result records, exit status and output of a finished command.
+    struct Output {
+        records: Vec<PartRecord>,
+        status: ExitStatus,
+        stderr: Vec<String>,
+        /// Output lines in order of arrival, only collected for buffered commands.
+        lines: Vec<Line>,
     }
 
-    #[cfg(feature = "test_lib")]
-    mod tests {
-        use super::parse_exec_time;
-
-        use crate::day;
-
-        #[test]
-        fn parses_execution_times() {
-            let res = parse_exec_time(
-                &[
-                    "Part 1: 0 (74.13ns @ 100000 samples)".into(),
-                    "Part 2: 10 (74.13ms @ 99999 samples)".into(),
-                    "".into(),
-                ],
-                day!(1),
-            );
-            assert_approx_eq!(res.total_nanos, 74130074.13_f64);
-            assert_eq!(res.part_1.unwrap(), "74.13ns");
-            assert_eq!(res.part_2.unwrap(), "74.13ms");
+    impl Output {
+        /// The result records of a day, including one for the part that was running when the
+        /// solution was stopped by a limit or crashed.
+        fn into_records(self, day: Day) -> Vec<PartRecord> {
+            let mut records = self.records;
+
+            // NOTE: a stopped solution does not emit a record for the part it was running.
+            let part = records.iter().map(|r| r.part).max().unwrap_or(0) + 1;
+
+            if let Some(status) = limits::exceeded_status(self.status.code(), &self.stderr) {
+                records.push(PartRecord::failed(day, part, status, None));
+            } else if !self.status.success() && !records.iter().any(|r| r.status.is_failure()) {
+                records.push(PartRecord::failed(
+                    day,
+                    part,
+                    Status::Crashed,
+                    Some(crash_reason(self.status, &self.stderr)),
+                ));
+            }
+
+            records
+        }
+    }
+
+    fn run_command(command: &mut Command, buffered: bool) -> Result<Output, Error> {
+        // spawn child command with piped stdout/stderr.
+        // forward (or buffer) output to stdout/stderr while collecting result records from stdout.
+
+        let mut cmd = command
+            .env(RECORDS_ENV, "1")
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()?;
+
+        let stdout = BufReader::new(cmd.stdout.take().ok_or(super::Error::BrokenPipe)?);
+        let stderr = BufReader::new(cmd.stderr.take().ok_or(super::Error::BrokenPipe)?);
+
+        let buffer = buffered.then(|| Arc::new(Mutex::new(vec![])));
+        let mut records = vec![];
+
+        let thread = {
+            let buffer = buffer.clone();
+            thread::spawn(move || {
+                stderr
+                    .lines()
+                    .map_while(Result::ok)
+                    .inspect(|line| emit(Line::Stderr(line.clone()), buffer.as_deref()))
+                    .collect::<Vec<_>>()
+            })
+        };
+
+        for line in stdout.lines() {
+            let line = line?;
+            match PartRecord::from_line(&line) {
+                Some(Ok(record)) => records.push(record),
+                Some(Err(e)) => emit(
+                    Line::Stderr(format!("Could not parse result record: {e}")),
+                    buffer.as_deref(),
+                ),
+                None => emit(Line::Stdout(line), buffer.as_deref()),
+            }
         }
 
-        #[test]
-        fn parses_with_patterns_in_input() {
-            let res = parse_exec_time(
-                &[
-                    "Part 1: @ @ @ ( ) ms (2s @ 5 samples)".into(),
-                    "Part 2: 10s (100ms @ 1 samples)".into(),
-                    "".into(),
-                ],
-                day!(1),
-            );
-            assert_approx_eq!(res.total_nanos, 2100000000_f64);
-            assert_eq!(res.part_1.unwrap(), "2s");
-            assert_eq!(res.part_2.unwrap(), "100ms");
+        let stderr = thread.join().unwrap();
+        let status = cmd.wait()?;
+
+        Ok(Output {
+            records,
+            status,
+            stderr,
+            lines: buffer.map_or_else(Vec::new, |buffer| mem::take(&mut buffer.lock().unwrap())),
+        })
+    }
+
+    fn crash_reason(status: ExitStatus, stderr: &[String]) -> String {
+        if stderr
+            .iter()
+            .any(|line| line.ends_with("has overflowed its stack"))
+        {
+            "stack overflow".into()
+        } else if let Some(code) = status.code() {
+            format!("exited with code {code}")
+        } else {
+            format!("exited with {status}")
         }
+    }
 
-        #[test]
-        fn parses_missing_parts() {
-            let res = parse_exec_time(
-                &[
-                    "Part 1: ✖        ".into(),
-                    "Part 2: ✖        ".into(),
-                    "".into(),
-                ],
-                day!(1),
-            );
-            assert_approx_eq!(res.total_nanos, 0_f64);
-            assert_eq!(res.part_1.is_none(), true);
-            assert_eq!(res.part_2.is_none(), true);
+    /// Print a line, or append it to `buffer` if the output is buffered.
+    fn emit(line: Line, buffer: Option<&Mutex<Vec<Line>>>) {
+        match buffer {
+            Some(buffer) => buffer.lock().unwrap().push(line),
+            None => line.print(),
         }
     }
 }
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::{thread, time::Duration};
+
+    use super::run_pool;
+    use crate::day;
+
+    #[test]
+    fn runs_days_in_parallel() {
+        let days = [day!(1), day!(2), day!(3), day!(4)];
+        let (running, most_running) = (AtomicUsize::new(0), AtomicUsize::new(0));
+        let mut finished = vec![];
+
+        run_pool(
+            &days,
+            4,
+            |day| {
+                let now_running = running.fetch_add(1, Ordering::SeqCst) + 1;
+                most_running.fetch_max(now_running, Ordering::SeqCst);
+                thread::sleep(Duration::from_millis(50));
+                running.fetch_sub(1, Ordering::SeqCst);
+                day
+            },
+            |day, output| finished.push((day, output)),
+        );
+
+        assert!(most_running.load(Ordering::SeqCst) > 1);
+        assert_eq!(finished, days.map(|day| (day, day)));
+    }
+}
@@ -1,63 +1,238 @@
 /// Encapsulates code that interacts with solution functions.
-use std::fmt::Display;
 use std::hint::black_box;
 use std::io::{Write, stdout};
-use std::process::Output;
 use std::time::{Duration, Instant};
-use std::{cmp, env, process};
+use std::{env, process};
 
 use crate::template::ANSI_BOLD;
-use crate::template::{ANSI_ITALIC, ANSI_RESET, Day, aoc_cli};
+use crate::template::answer::{Answer, ToAnswer};
+use crate::template::answers::{Check, Ledger, Outcome};
+use crate::template::bench::{BenchOptions, BenchStats};
+use crate::template::client::{Backend, Client, Verdict};
+use crate::template::limits::{self, Limits};
+use crate::template::panics::{self, Panic};
+use crate::template::record::{PARSE_PART, PartRecord, RECORDS_ENV, Status};
+use crate::template::{ANSI_ITALIC, ANSI_RESET, Day, YEAR_ENV, Year, aoc_cli, input_sets};
 
-pub fn run_part<I: Copy, T: Display>(func: impl Fn(I) -> Option<T>, input: I, day: Day, part: u8) {
+/// Options that control how solution parts are run.
+#[derive(Clone, Debug, Default)]
+pub struct Options {
+    /// Bench each part instead of running it once.
+    pub timed: bool,
+    pub bench: BenchOptions,
+    /// Time limit of the first run of each part and memory limit of the solution process.
+    pub limits: Limits,
+}
+
+impl Options {
+    /// Read the options from the command-line arguments of the current process.
+    pub fn from_args() -> Self {
+        match Self::from_arguments(&mut pico_args::Arguments::from_env()) {
+            Ok(options) => options,
+            Err(e) => {
+                eprintln!("Unexpected command-line input: {e}");
+                process::exit(1);
+            }
+        }
+    }
+
+    /// Parse the options from `args`, consuming the matched flags.
+    pub fn from_arguments(args: &mut pico_args::Arguments) -> Result<Self, pico_args::Error> {
+        let defaults = BenchOptions::default();
+
+        Ok(Self {
+            limits: Limits::from_arguments(args)?,
+            timed: args.contains("--time"),
+            bench: BenchOptions {
+                warmup: args
+                    .opt_value_from_str("--warmup")?
+                    .unwrap_or(defaults.warmup),
+                budget: args
+                    .opt_value_from_str("--budget-ms")?
+                    .map_or(defaults.budget, Duration::from_millis),
+                min_samples: args
+                    .opt_value_from_str("--min-samples")?
+                    .unwrap_or(defaults.min_samples),
+                max_samples: args
+                    .opt_value_from_str("--max-samples")?
+                    .unwrap_or(defaults.max_samples),
+            },
+        })
+    }
+
+    /// Converts the options to command-line arguments of a solution binary.
+    pub fn to_args(&self) -> Vec<String> {
+        let mut args = self.limits.to_args();
+        if self.timed {
+            args.push("--time".to_string());
+            args.extend(self.bench.to_args());
+        }
+        args
+    }
+}
+
+pub fn run_part<I: Copy, T: ToAnswer>(
+    func: impl Fn(I) -> T,
+    input: I,
+    day: Day,
+    part: u8,
+) -> PartRecord {
+    run_part_with(func, input, day, part, &Options::from_args())
+}
+
+/// Same as [`run_part`], with explicit options instead of the ones of the current process.
+pub fn run_part_with<I: Copy, T: ToAnswer>(
+    func: impl Fn(I) -> T,
+    input: I,
+    day: Day,
+    part: u8,
+    options: &Options,
+) -> PartRecord {
     let part_str = format!("Part {part}");
 
-    let (result, duration, samples) =
-        run_timed(func, input, |result| print_result(result, &part_str, ""));
+    let timed = panics::catch(|| {
+        run_timed(func, input, options, |result| {
+            print_result(&result.to_answer(), &part_str, "");
+        })
+    });
+
+    let (result, duration, samples, stats) = match timed {
+        Ok(timed) => timed,
+        Err(panic) => return report_panic(day, part, &part_str, &panic),
+    };
+
+    let answer = result.to_answer();
 
-    print_result(&result, &part_str, &format_duration(&duration, samples));
+    print_result(
+        &answer,
+        &part_str,
+        &format_duration(&duration, stats.as_ref()),
+    );
 
-    if let Some(result) = result {
-        submit_result(result, day, part);
+    let record = PartRecord {
+        day,
+        part,
+        answer: answer.text().map(ToString::to_string),
+        duration,
+        samples,
+        stats,
+        status: match answer {
+            Answer::Value(_) | Answer::Rendered { .. } => Status::Solved,
+            Answer::Unsolved => Status::Unsolved,
+            Answer::Failed(_) => Status::Failed,
+        },
+        error: matches!(answer, Answer::Failed(_)).then(|| answer.to_string()),
+    };
+
+    emit_record(&record);
+
+    if answer.text().is_some() {
+        submit_result(&answer, day, part);
+    }
+
+    record
+}
+
+/// Run the parse phase of a solution and time it separately from the parts.
+/// Returns the parsed input, which is shared by all parts, or [`None`] if parsing panicked.
+pub fn run_parse<I: Copy, T>(
+    func: impl Fn(I) -> T,
+    input: I,
+    day: Day,
+    options: &Options,
+) -> (Option<T>, PartRecord) {
+    let timed = panics::catch(|| {
+        run_timed(func, input, options, |_| {
+            print!("Parse:");
+        })
+    });
+
+    let (parsed, duration, samples, stats) = match timed {
+        Ok(timed) => timed,
+        Err(panic) => return (None, report_panic(day, PARSE_PART, "Parse", &panic)),
+    };
+
+    print!("\r");
+    println!("Parse:{}", format_duration(&duration, stats.as_ref()));
+
+    let record = PartRecord {
+        day,
+        part: PARSE_PART,
+        answer: None,
+        duration,
+        samples,
+        stats,
+        status: Status::Solved,
+        error: None,
+    };
+
+    emit_record(&record);
+
+    (Some(parsed), record)
+}
+
+/// Print the panic of a part or the parse phase and emit its record.
+fn report_panic(day: Day, part: u8, label: &str, panic: &Panic) -> PartRecord {
+    print!("\r");
+    println!("{label}: ✖ {panic}");
+
+    let record = PartRecord::failed(day, part, Status::Panicked, Some(panic.to_string()));
+    emit_record(&record);
+    record
+}
+
+/// Print the machine-readable record of a part when running under `run_multi`.
+fn emit_record(record: &PartRecord) {
+    if env::var_os(RECORDS_ENV).is_some() {
+        println!("{}", record.to_line());
     }
 }
 
-/// Run a solution part. The behavior differs depending on whether we are running a release or debug build:
-///  1. in debug, the function is executed once.
-///  2. in release, the function is benched (approx. 1 second of execution time or 10 samples, whatever take longer.)
+/// Run a solution part. The behavior differs depending on whether the part is timed:
+///  1. by default, the function is executed once.
+///  2. with `--time`, the function is benched (see [`BenchOptions`]) and the median of all samples is reported.
 fn run_timed<I: Copy, T>(
     func: impl Fn(I) -> T,
     input: I,
+    options: &Options,
     hook: impl Fn(&T),
-) -> (T, Duration, u128) {
+) -> (T, Duration, u128, Option<BenchStats>) {
     let timer = Instant::now();
     let result = {
         #[cfg(feature = "dhat-heap")]
         let _profiler = dhat::Profiler::new_heap();
 
-        func(input)
+        // NOTE: the part timeout only limits this first run, benching repeats it many times.
+        limits::guard(|| func(input))
     };
     let base_time = timer.elapsed();
 
     hook(&result);
 
-    let run = if std::env::args().any(|x| x == "--time") {
-        bench(func, input, &base_time)
+    if options.timed {
+        let stats = bench(func, input, &base_time, &options.bench);
+        (result, stats.median, stats.samples, Some(stats))
     } else {
-        (base_time, 1)
-    };
-
-    (result, run.0, run.1)
+        (result, base_time, 1, None)
+    }
 }
 
-fn bench<I: Copy, T>(func: impl Fn(I) -> T, input: I, base_time: &Duration) -> (Duration, u128) {
+fn bench<I: Copy, T>(
+    func: impl Fn(I) -> T,
+    input: I,
+    base_time: &Duration,
+    options: &BenchOptions,
+) -> BenchStats {
     let mut stdout = stdout();
 
     print!(" > {ANSI_ITALIC}benching{ANSI_RESET}");
     let _ = stdout.flush();
 
-    let bench_iterations =
-        (Duration::from_secs(1).as_nanos() / cmp::max(base_time.as_nanos(), 10)).clamp(10, 10000);
+    for _ in 0..options.warmup_count(base_time) {
+        black_box(func(black_box(input)));
+    }
+
+    let bench_iterations = options.sample_count(base_time);
 
     let mut timers: Vec<Duration> = vec![];
 
@@ -67,54 +242,58 @@ fn bench<I: Copy, T>(func: impl Fn(I) -> T, input: I, base_time: &Duration) -> (
         timers.push(timer.elapsed());
     }
 
-    (
-        #[allow(clippy::cast_possible_truncation)]
-        Duration::from_nanos(average_duration(&timers) as u64),
-        bench_iterations,
-    )
+    // NOTE: `bench_iterations` is at least 1, so there is always a sample.
+    BenchStats::from_samples(&timers).unwrap()
 }
 
-fn average_duration(numbers: &[Duration]) -> u128 {
-    numbers
-        .iter()
-        .map(std::time::Duration::as_nanos)
-        .sum::<u128>()
-        / numbers.len() as u128
-}
-
-fn format_duration(duration: &Duration, samples: u128) -> String {
-    if samples == 1 {
-        format!(" ({duration:.1?})")
-    } else {
-        format!(" ({duration:.1?} @ {samples} samples)")
+fn format_duration(duration: &Duration, stats: Option<&BenchStats>) -> String {
+    match stats {
+        None => format!(" ({duration:.1?})"),
+        Some(stats) => format!(
+            " ({duration:.1?} @ {} samples, min {:.1?}, p95 {:.1?}, σ {:.1?}, {} outliers)",
+            stats.samples, stats.min, stats.p95, stats.stddev, stats.outliers
+        ),
     }
 }
 
-fn print_result<T: Display>(result: &Option<T>, part: &str, duration_str: &str) {
+fn print_result(answer: &Answer, part: &str, duration_str: &str) {
     let is_intermediate_result = duration_str.is_empty();
 
-    match result {
-        Some(result) => {
-            if result.to_string().contains('\n') {
-                let str = format!("{part}: ▼ {duration_str}");
-                if is_intermediate_result {
-                    print!("{str}");
-                } else {
-                    print!("\r");
-                    println!("{str}");
-                    println!("{result}");
+    match answer {
+        Answer::Rendered { output, letters } => {
+            let str = match letters {
+                Some(letters) => {
+                    format!("{part}: {ANSI_BOLD}{letters}{ANSI_RESET} ▼{duration_str}")
                 }
+                None => format!("{part}: ▼ {duration_str}"),
+            };
+            if is_intermediate_result {
+                print!("{str}");
             } else {
-                let str = format!("{part}: {ANSI_BOLD}{result}{ANSI_RESET}{duration_str}");
-                if is_intermediate_result {
-                    print!("{str}");
-                } else {
-                    print!("\r");
-                    println!("{str}");
-                }
+                print!("\r");
+                println!("{str}");
+                println!("{output}");
+            }
+        }
+        Answer::Value(value) => {
+            let str = format!("{part}: {ANSI_BOLD}{value}{ANSI_RESET}{duration_str}");
+            if is_intermediate_result {
+                print!("{str}");
+            } else {
+                print!("\r");
+                println!("{str}");
+            }
+        }
+        Answer::Failed(_) => {
+            let str = format!("{part}: ✖ {answer}{duration_str}");
+            if is_intermediate_result {
+                print!("{str}");
+            } else {
+                print!("\r");
+                println!("{str}");
             }
         }
-        None => {
+        Answer::Unsolved => {
             if is_intermediate_result {
                 print!("{part}: ✖");
             } else {
@@ -125,18 +304,13 @@ fn print_result<T: Display>(result: &Option<T>, part: &str, duration_str: &str)
     }
 }
 
-/// Parse the arguments passed to `solve` and try to submit one part of the solution if:
-///  1. we are in `--release` mode.
-///  2. aoc-cli is installed.
-fn submit_result<T: Display>(
-    result: T,
-    day: Day,
-    part: u8,
-) -> Option<Result<Output, aoc_cli::AocCommandError>> {
+/// Parse the arguments passed to `solve` and try to submit one part of the solution if it
+/// matches the part passed to `--submit`.
+fn submit_result(answer: &Answer, day: Day, part: u8) {
     let args: Vec<String> = env::args().collect();
 
     if !args.contains(&"--submit".into()) {
-        return None;
+        return;
     }
 
     if args.len() < 3 {
@@ -152,9 +326,72 @@ fn submit_result<T: Display>(
     };
 
     if part_submit != part {
-        return None;
+        return;
+    }
+
+    if input_sets::is_requested() {
+        eprintln!("Answers can not be submitted when running all input sets.");
+        process::exit(1);
+    }
+
+    // NOTE: `cargo solve` passes the selected year to the solution binary.
+    let Some(year) = Year::from_env() else {
+        eprintln!("Could not submit: `{YEAR_ENV}` is not set to a valid year.");
+        process::exit(1);
+    };
+
+    // NOTE: rendered answers that are not made of known letters have to be read first.
+    let Some(answer) = answer.submission() else {
+        eprintln!(
+            "The rendered answer could not be recognized as letters, read it above and submit it by hand."
+        );
+        process::exit(1);
+    };
+
+    let mut ledger = match Ledger::read_from_file(year, day) {
+        Ok(ledger) => ledger,
+        Err(e) => {
+            eprintln!("Could not read the answer ledger of day {day}: {e}");
+            process::exit(1);
+        }
+    };
+
+    // NOTE: answers that the ledger already has a verdict for are checked locally.
+    let check = ledger.check(part, answer);
+    if check != Check::Submit {
+        println!("Not submitting: {check}");
+        return;
+    }
+
+    let verdict = match Backend::from_env() {
+        Backend::Native => submit(year, day, part, answer),
+        Backend::AocCli => submit_with_aoc_cli(year, day, part, answer),
+    };
+
+    if let Some(outcome) = Outcome::from_verdict(&verdict) {
+        ledger.record(part, answer, outcome);
+        if let Err(e) = ledger.store_file(year, day) {
+            eprintln!("failed to store the answer ledger: {e}");
+        }
     }
+}
+
+fn submit(year: Year, day: Day, part: u8, answer: &str) -> Verdict {
+    println!("Submitting result...");
 
+    match Client::from_env().and_then(|client| client.submit(year, day, part, answer)) {
+        Ok(submission) => {
+            print!("{}", submission.message);
+            submission.verdict
+        }
+        Err(e) => {
+            eprintln!("failed to submit result: {e}");
+            process::exit(1);
+        }
+    }
+}
+
+fn submit_with_aoc_cli(year: Year, day: Day, part: u8, answer: &str) -> Verdict {
     if aoc_cli::check().is_err() {
         eprintln!(
             "command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it."
@@ -163,5 +400,11 @@ fn submit_result<T: Display>(
     }
 
     println!("Submitting result via aoc-cli...");
-    Some(aoc_cli::submit(day, part, &result.to_string()))
+    match aoc_cli::submit(year, day, part, answer) {
+        Ok(output) => Verdict::from_message(&String::from_utf8_lossy(&output.stdout)),
+        Err(e) => {
+            eprintln!("failed to call aoc-cli: {e}");
+            process::exit(1);
+        }
+    }
 }
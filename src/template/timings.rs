@@ -1,7 +1,14 @@
-use std::{collections::HashMap, fs, io::Error, str::FromStr};
+use std::{
+    collections::{BTreeMap, HashMap},
+    fs,
+    io::{Error, ErrorKind},
+    str::FromStr,
+};
 use tinyjson::JsonValue;
 
-use crate::template::Day;
+use crate::template::bench::BenchStats;
+use crate::template::record::{PARSE_PART, PartRecord, Status};
+use crate::template::{Day, Year};
 
 static TIMINGS_FILE_PATH: &str = "./data/timings.json";
 
@@ -12,6 +19,53 @@ pub struct Timing {
     pub part_1: Option<String>,
     pub part_2: Option<String>,
     pub total_nanos: f64,
+    pub part_1_stats: Option<BenchStats>,
+    pub part_2_stats: Option<BenchStats>,
+    /// Time of the parse phase, for solutions that declare one.
+    pub parse: Option<String>,
+    pub parse_stats: Option<BenchStats>,
+    /// Set if a part of the day failed, e.g. by panicking or exceeding its time limit.
+    pub failure: Option<Status>,
+}
+
+impl Timing {
+    /// Collect the timings of a day from the result records of its parts.
+    #[allow(clippy::cast_precision_loss)]
+    pub fn from_records(day: Day, records: &[PartRecord]) -> Self {
+        let mut timing = Timing {
+            day,
+            part_1: None,
+            part_2: None,
+            total_nanos: 0_f64,
+            part_1_stats: None,
+            part_2_stats: None,
+            parse: None,
+            parse_stats: None,
+            failure: None,
+        };
+
+        for record in records
+            .iter()
+            .filter(|r| r.day == day && r.status == Status::Solved)
+        {
+            let duration = Some(format!("{:.1?}", record.duration));
+            let stats = record.stats.clone();
+            match record.part {
+                PARSE_PART => (timing.parse, timing.parse_stats) = (duration, stats),
+                1 => (timing.part_1, timing.part_1_stats) = (duration, stats),
+                2 => (timing.part_2, timing.part_2_stats) = (duration, stats),
+                _ => continue,
+            }
+            timing.total_nanos += record.duration.as_nanos() as f64;
+        }
+
+        timing.failure = records
+            .iter()
+            .find(|r| r.day == day && r.status.is_failure())
+            .map(|r| r.status);
+
+        timing
+    }
 }
 
 /// Represents benchmark times for a set of days.
@@ -22,21 +76,6 @@ pub struct Timings {
 }
 
 impl Timings {
-    /// Dehydrate timings to a JSON file.
-    pub fn store_file(&self) -> Result<(), Error> {
-        let json = JsonValue::from(self.clone());
-        let mut file = fs::File::create(TIMINGS_FILE_PATH)?;
-        json.format_to(&mut file)
-    }
-
-    /// Rehydrate timings from a JSON file. If not present, returns empty timings.
-    pub fn read_from_file() -> Self {
-        fs::read_to_string(TIMINGS_FILE_PATH)
-            .map_err(|x| x.to_string())
-            .and_then(Timings::try_from)
-            .unwrap_or_default()
-    }
-
     /// Merge two sets of timings, overwriting `self` with `other` if present.
     pub fn merge(&self, new: &Self) -> Self {
         let mut data: Vec<Timing> = vec![];
@@ -51,7 +90,7 @@ impl Timings {
             }
         }
 
-        data.sort_unstable_by(|a, b| a.day.cmp(&b.day));
+        data.sort_unstable_by_key(|a| a.day);
         Timings { data }
     }
 
@@ -61,9 +100,102 @@ impl Timings {
     }
 
     pub fn is_day_complete(&self, day: Day) -> bool {
-        self.data
-            .iter()
-            .any(|t| t.day == day && t.part_1.is_some() && t.part_2.is_some())
+        self.data.iter().any(|t| {
+            t.day == day && t.part_1.is_some() && t.part_2.is_some() && t.failure.is_none()
+        })
+    }
+}
+
+/// Represents the benchmark times of every year, grouped by year.
+/// This is the content of the timings file.
+#[derive(Clone, Debug, Default)]
+pub struct StoredTimings {
+    pub years: BTreeMap<Year, Timings>,
+}
+
+impl StoredTimings {
+    /// Dehydrate timings to a JSON file.
+    pub fn store_file(&self) -> Result<(), Error> {
+        let json = JsonValue::from(self.clone());
+        let mut file = fs::File::create(TIMINGS_FILE_PATH)?;
+        json.format_to(&mut file)
+    }
+
+    /// Rehydrate timings from a JSON file. If not present, returns empty timings.
+    /// Fails if the file can't be read or parsed, so that it is not overwritten by a later store.
+    pub fn read_from_file() -> Result<Self, String> {
+        match fs::read_to_string(TIMINGS_FILE_PATH) {
+            Ok(contents) => StoredTimings::try_from(contents),
+            Err(e) if e.kind() == ErrorKind::NotFound => Ok(StoredTimings::default()),
+            Err(e) => Err(e.to_string()),
+        }
+    }
+
+    /// Read timings of the layout without years, as stored before multi-year support, as the
+    /// timings of `year`.
+    fn from_legacy(json: &JsonValue, year: Year) -> Result<Self, String> {
+        Ok(StoredTimings::default().merge(year, &Timings::try_from(json)?))
+    }
+
+    /// Timings of a single year. Empty if the year has no stored timings.
+    pub fn get(&self, year: Year) -> Timings {
+        self.years.get(&year).cloned().unwrap_or_default()
+    }
+
+    /// Merge the timings of a year, overwriting stored days with the ones in `new`.
+    pub fn merge(&self, year: Year, new: &Timings) -> Self {
+        let mut years = self.years.clone();
+        years.insert(year, self.get(year).merge(new));
+        StoredTimings { years }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<StoredTimings> for JsonValue {
+    fn from(value: StoredTimings) -> Self {
+        let years = value
+            .years
+            .into_iter()
+            .map(|(year, timings)| (year.to_string(), JsonValue::from(timings)))
+            .collect();
+
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+        map.insert("years".into(), JsonValue::Object(years));
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<String> for StoredTimings {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let json = JsonValue::from_str(&value).or(Err("not valid JSON file."))?;
+
+        let document = json
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("expected JSON document to be an object.")?;
+
+        if !document.contains_key("years") && document.contains_key("data") {
+            let year = Year::from_env()
+                .ok_or("found timings without years, set `AOC_YEAR` to the year they belong to.")?;
+            return StoredTimings::from_legacy(&json, year);
+        }
+
+        let json_years = document
+            .get("years")
+            .ok_or("expected JSON document to have key `years`.")?
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("expected `json.years` to be an object.")?;
+
+        let mut years = BTreeMap::new();
+
+        for (year, timings) in json_years {
+            let year = Year::from_str(year).map_err(|e| format!("`{year}`: {e}."))?;
+            years.insert(year, Timings::try_from(timings)?);
+        }
+
+        Ok(StoredTimings { years })
     }
 }
 
@@ -87,7 +219,14 @@ impl TryFrom<String> for Timings {
 
     fn try_from(value: String) -> Result<Self, Self::Error> {
         let json = JsonValue::from_str(&value).or(Err("not valid JSON file."))?;
+        Timings::try_from(&json)
+    }
+}
 
+impl TryFrom<&JsonValue> for Timings {
+    type Error = String;
+
+    fn try_from(json: &JsonValue) -> Result<Self, Self::Error> {
         let json_data = json
             .get::<HashMap<String, JsonValue>>()
             .ok_or("expected JSON document to be an object.")?
@@ -133,6 +272,24 @@ impl From<&Timing> for JsonValue {
             },
         );
 
+        if let Some(parse) = &value.parse {
+            map.insert("parse".into(), JsonValue::String(parse.clone()));
+        }
+
+        if let Some(failure) = value.failure {
+            map.insert("failure".into(), JsonValue::String(failure.as_str().into()));
+        }
+
+        for (key, stats) in [
+            ("part_1_stats", &value.part_1_stats),
+            ("part_2_stats", &value.part_2_stats),
+            ("parse_stats", &value.parse_stats),
+        ] {
+            if let Some(stats) = stats {
+                map.insert(key.into(), JsonValue::from(stats));
+            }
+        }
+
         JsonValue::Object(map)
     }
 }
@@ -166,11 +323,44 @@ impl TryFrom<&JsonValue> for Timing {
             .and_then(|v| v.get::<f64>().copied())
             .ok_or("Expected timing.total_nanos to be a number.")?;
 
+        // NOTE: the parse phase and stats are optional, timings stored before they were recorded do not have them.
+        let parse = json
+            .get("parse")
+            .map(|v| if v.is_null() { None } else { v.get::<String>() })
+            .unwrap_or_default();
+
+        let parse_stats = json
+            .get("parse_stats")
+            .map(BenchStats::try_from)
+            .transpose()?;
+
+        let part_1_stats = json
+            .get("part_1_stats")
+            .map(BenchStats::try_from)
+            .transpose()?;
+
+        let part_2_stats = json
+            .get("part_2_stats")
+            .map(BenchStats::try_from)
+            .transpose()?;
+
+        // NOTE: only days that were stopped by a limit have a failure.
+        let failure = json
+            .get("failure")
+            .and_then(|v| v.get::<String>())
+            .map(|v| v.parse::<Status>())
+            .transpose()?;
+
         Ok(Timing {
             day,
             part_1: part_1.cloned(),
             part_2: part_2.cloned(),
             total_nanos,
+            part_1_stats,
+            part_2_stats,
+            parse: parse.cloned(),
+            parse_stats,
+            failure,
         })
     }
 }
@@ -191,25 +381,43 @@ mod tests {
                     part_1: Some("10ms".into()),
                     part_2: Some("20ms".into()),
                     total_nanos: 3e+10,
+                    part_1_stats: None,
+                    part_2_stats: None,
+                    parse: None,
+                    parse_stats: None,
+                    failure: None,
                 },
                 Timing {
                     day: day!(2),
                     part_1: Some("30ms".into()),
                     part_2: Some("40ms".into()),
                     total_nanos: 7e+10,
+                    part_1_stats: None,
+                    part_2_stats: None,
+                    parse: None,
+                    parse_stats: None,
+                    failure: None,
                 },
                 Timing {
                     day: day!(4),
                     part_1: Some("40ms".into()),
                     part_2: None,
                     total_nanos: 4e+10,
+                    part_1_stats: None,
+                    part_2_stats: None,
+                    parse: None,
+                    parse_stats: None,
+                    failure: None,
                 },
             ],
         }
     }
 
     mod deserialization {
-        use crate::{day, template::timings::Timings};
+        use crate::{
+            day,
+            template::{record::Status, timings::Timings},
+        };
 
         #[test]
         fn handles_json_timings() {
@@ -223,6 +431,35 @@ mod tests {
             assert_eq!(timing.total_nanos, 1_000_000_000_f64);
         }
 
+        #[test]
+        fn handles_json_stats() {
+            let json = r#"{ "data": [{ "day": "01", "part_1": "1ms", "part_2": null, "total_nanos": 1000000, "part_1_stats": { "mean_nanos": 1100000, "median_nanos": 1000000, "min_nanos": 900000, "p95_nanos": 1500000, "stddev_nanos": 100000, "samples": 100, "outliers": 2 } }] }"#.to_string();
+            let timings = Timings::try_from(json).unwrap();
+            let timing = timings.data.first().unwrap();
+            let stats = timing.part_1_stats.as_ref().unwrap();
+            assert_eq!(stats.median.as_nanos(), 1_000_000);
+            assert_eq!(stats.outliers, 2);
+            assert!(timing.part_2_stats.is_none());
+            assert!(timing.parse.is_none());
+        }
+
+        #[test]
+        fn handles_json_parse_phase() {
+            let json = r#"{ "data": [{ "day": "01", "parse": "3ms", "part_1": "1ms", "part_2": null, "total_nanos": 4000000 }] }"#.to_string();
+            let timings = Timings::try_from(json).unwrap();
+            let timing = timings.data.first().unwrap();
+            assert_eq!(timing.parse, Some("3ms".to_string()));
+            assert!(timing.parse_stats.is_none());
+        }
+
+        #[test]
+        fn handles_json_failure() {
+            let json = r#"{ "data": [{ "day": "02", "part_1": null, "part_2": null, "total_nanos": 0, "failure": "timed_out" }] }"#.to_string();
+            let timings = Timings::try_from(json).unwrap();
+            assert_eq!(timings.data[0].failure, Some(Status::TimedOut));
+            assert!(!timings.is_day_complete(day!(2)));
+        }
+
         #[test]
         fn handles_empty_timings() {
             let json = r#"{ "data": [] }"#.to_string();
@@ -282,10 +519,15 @@ mod tests {
                     part_1: Some("1ms".into()),
                     part_2: Some("2ms".into()),
                     total_nanos: 3_000_000_000_f64,
+                    part_1_stats: None,
+                    part_2_stats: None,
+                    parse: None,
+                    parse_stats: None,
+                    failure: None,
                 }],
             };
 
-            assert_eq!(timings.is_day_complete(&day!(1)), true);
+            assert_eq!(timings.is_day_complete(day!(1)), true);
         }
 
         #[test]
@@ -296,10 +538,15 @@ mod tests {
                     part_1: Some("1ms".into()),
                     part_2: None,
                     total_nanos: 1_000_000_000_f64,
+                    part_1_stats: None,
+                    part_2_stats: None,
+                    parse: None,
+                    parse_stats: None,
+                    failure: None,
                 }],
             };
 
-            assert_eq!(timings.is_day_complete(&day!(1)), false);
+            assert_eq!(timings.is_day_complete(day!(1)), false);
         }
 
         #[test]
@@ -310,10 +557,160 @@ mod tests {
                     part_1: None,
                     part_2: None,
                     total_nanos: 0.0,
+                    part_1_stats: None,
+                    part_2_stats: None,
+                    parse: None,
+                    parse_stats: None,
+                    failure: None,
                 }],
             };
 
-            assert_eq!(timings.is_day_complete(&day!(1)), false);
+            assert_eq!(timings.is_day_complete(day!(1)), false);
+        }
+    }
+
+    mod from_records {
+        use std::time::Duration;
+
+        use crate::{
+            day,
+            template::bench::BenchStats,
+            template::record::{PartRecord, Status},
+            template::timings::Timing,
+        };
+
+        fn get_mock_record(part: u8, nanos: u64, status: Status) -> PartRecord {
+            PartRecord {
+                day: day!(1),
+                part,
+                answer: Some("(42) @ 1ms".into()),
+                duration: Duration::from_nanos(nanos),
+                samples: 100,
+                stats: BenchStats::from_samples(&[Duration::from_nanos(nanos)]),
+                status,
+                error: None,
+            }
+        }
+
+        #[test]
+        fn collects_solved_parts() {
+            let timing = Timing::from_records(
+                day!(1),
+                &[
+                    get_mock_record(1, 74_130, Status::Solved),
+                    get_mock_record(2, 74_130_000, Status::Solved),
+                ],
+            );
+            assert_eq!(timing.part_1.unwrap(), "74.1µs");
+            assert_eq!(timing.part_2.unwrap(), "74.1ms");
+            assert_eq!(timing.total_nanos, 74_204_130_f64);
+            assert_eq!(
+                timing.part_1_stats.unwrap().median,
+                Duration::from_nanos(74_130)
+            );
+            assert_eq!(
+                timing.part_2_stats.unwrap().median,
+                Duration::from_nanos(74_130_000)
+            );
+        }
+
+        #[test]
+        fn collects_parse_phase() {
+            let timing = Timing::from_records(
+                day!(1),
+                &[
+                    get_mock_record(0, 1_000, Status::Solved),
+                    get_mock_record(1, 2_000, Status::Solved),
+                ],
+            );
+            assert_eq!(timing.parse.unwrap(), "1.0µs");
+            assert_eq!(timing.part_1.unwrap(), "2.0µs");
+            assert_eq!(timing.total_nanos, 3_000_f64);
+        }
+
+        #[test]
+        fn skips_unsolved_parts() {
+            let timing = Timing::from_records(
+                day!(1),
+                &[
+                    get_mock_record(1, 2_000, Status::Solved),
+                    get_mock_record(2, 0, Status::Unsolved),
+                ],
+            );
+            assert_eq!(timing.part_1.unwrap(), "2.0µs");
+            assert_eq!(timing.part_2, None);
+            assert_eq!(timing.total_nanos, 2_000_f64);
+        }
+
+        #[test]
+        fn records_exceeded_limits() {
+            let timing = Timing::from_records(
+                day!(1),
+                &[
+                    get_mock_record(1, 2_000, Status::Solved),
+                    get_mock_record(2, 0, Status::OutOfMemory),
+                ],
+            );
+            assert_eq!(timing.part_2, None);
+            assert_eq!(timing.failure, Some(Status::OutOfMemory));
+        }
+    }
+
+    mod stored_timings {
+        use std::str::FromStr;
+
+        use tinyjson::JsonValue;
+
+        use super::get_mock_timings;
+        use crate::{day, template::timings::StoredTimings, year};
+
+        #[test]
+        fn handles_json_years() {
+            let json = r#"{ "years": { "2024": { "data": [{ "day": "01", "part_1": "1ms", "part_2": null, "total_nanos": 1000000 }] }, "2025": { "data": [] } } }"#.to_string();
+            let stored = StoredTimings::try_from(json).unwrap();
+            assert_eq!(stored.years.len(), 2);
+            assert_eq!(stored.get(year!(2024)).data[0].day, day!(1));
+            assert!(stored.get(year!(2025)).data.is_empty());
+            assert!(stored.get(year!(2023)).data.is_empty());
+        }
+
+        #[test]
+        #[should_panic]
+        fn panics_for_invalid_years() {
+            let json = r#"{ "years": { "1999": { "data": [] } } }"#.to_string();
+            StoredTimings::try_from(json).unwrap();
+        }
+
+        #[test]
+        fn migrates_legacy_json() {
+            let json = r#"{ "data": [{ "day": "01", "part_1": "1ms", "part_2": null, "total_nanos": 1000000 }] }"#;
+            let json = JsonValue::from_str(json).unwrap();
+            let stored = StoredTimings::from_legacy(&json, year!(2024)).unwrap();
+            assert_eq!(stored.years.len(), 1);
+            assert_eq!(stored.get(year!(2024)).data[0].day, day!(1));
+
+            let json = JsonValue::from(stored).stringify().unwrap();
+            let parsed = StoredTimings::try_from(json).unwrap();
+            assert_eq!(parsed.get(year!(2024)).data.len(), 1);
+        }
+
+        #[test]
+        fn round_trips_json() {
+            let stored = StoredTimings::default().merge(year!(2024), &get_mock_timings());
+            let json = JsonValue::from(stored).stringify().unwrap();
+            let parsed = StoredTimings::try_from(json).unwrap();
+            assert_eq!(parsed.get(year!(2024)).data.len(), 3);
+        }
+
+        #[test]
+        fn merges_years_separately() {
+            let stored = StoredTimings::default()
+                .merge(year!(2024), &get_mock_timings())
+                .merge(year!(2025), &get_mock_timings());
+            let merged = stored.merge(year!(2025), &get_mock_timings());
+            assert_eq!(merged.years.len(), 2);
+            assert_eq!(merged.get(year!(2024)).data.len(), 3);
+            assert_eq!(merged.get(year!(2025)).data.len(), 3);
         }
     }
 
@@ -334,6 +731,11 @@ mod tests {
                     part_1: None,
                     part_2: None,
                     total_nanos: 0_f64,
+                    part_1_stats: None,
+                    part_2_stats: None,
+                    parse: None,
+                    parse_stats: None,
+                    failure: None,
                 }],
             };
             let merged = timings.merge(&other);
@@ -354,6 +756,11 @@ mod tests {
                     part_1: None,
                     part_2: None,
                     total_nanos: 0_f64,
+                    part_1_stats: None,
+                    part_2_stats: None,
+                    parse: None,
+                    parse_stats: None,
+                    failure: None,
                 }],
             };
             let merged = timings.merge(&other);
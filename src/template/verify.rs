@@ -0,0 +1,139 @@
+/// Module that checks the results of solved parts against their known correct answers.
+use crate::template::record::PartRecord;
+use crate::template::{ANSI_BOLD, ANSI_RESET, Day};
+
+/// Result of a solved part, compared to its known answer.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PartVerification {
+    pub day: Day,
+    pub part: u8,
+    pub expected: String,
+    /// The result of the current solution, [`None`] if the part did not return one.
+    pub actual: Option<String>,
+}
+
+impl PartVerification {
+    pub fn passed(&self) -> bool {
+        self.actual
+            .as_deref()
+            .is_some_and(|actual| actual.trim() == self.expected)
+    }
+}
+
+/// Compare the records of a day with the known answers of its parts.
+pub fn verify(
+    day: Day,
+    expected: &[(u8, String)],
+    records: &[PartRecord],
+) -> Vec<PartVerification> {
+    expected
+        .iter()
+        .map(|(part, answer)| PartVerification {
+            day,
+            part: *part,
+            expected: answer.clone(),
+            actual: records
+                .iter()
+                .find(|r| r.day == day && r.part == *part)
+                .and_then(|r| r.answer.clone()),
+        })
+        .collect()
+}
+
+/// Shortens answers that span multiple lines to fit in a table cell.
+fn format_answer(answer: &str) -> String {
+    let answer = answer.trim();
+    match answer.lines().count() {
+        0 | 1 => answer.to_string(),
+        lines => format!("▼ ({lines} lines)"),
+    }
+}
+
+/// Print a pass / fail table. Returns `true` if any part failed.
+pub fn print_report(verifications: &[PartVerification]) -> bool {
+    println!("\n{ANSI_BOLD}Verification{ANSI_RESET}");
+    println!("------");
+
+    let rows: Vec<[String; 5]> = verifications
+        .iter()
+        .map(|v| {
+            [
+                format!("Day {}", v.day),
+                format!("Part {}", v.part),
+                format_answer(&v.expected),
+                v.actual
+                    .as_deref()
+                    .map_or_else(|| "✖".into(), format_answer),
+                if v.passed() { "pass" } else { "FAIL" }.into(),
+            ]
+        })
+        .collect();
+
+    let header = ["Day", "Part", "Expected", "Actual", "Status"].map(String::from);
+
+    let mut widths = header.clone().map(|cell| cell.chars().count());
+    for row in &rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+
+    for row in std::iter::once(&header).chain(&rows) {
+        let cells: Vec<String> = row
+            .iter()
+            .zip(widths)
+            .map(|(cell, width)| format!("{cell:<width$}"))
+            .collect();
+        println!("{}", cells.join("  ").trim_end());
+    }
+
+    let failures = verifications.iter().filter(|v| !v.passed()).count();
+
+    println!();
+    if failures > 0 {
+        println!("{failures} of {} part(s) failed.", verifications.len());
+    } else {
+        println!("All {} part(s) passed.", verifications.len());
+    }
+
+    failures > 0
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::verify;
+    use crate::day;
+    use crate::template::record::PartRecord;
+
+    #[test]
+    fn compares_results_with_known_answers() {
+        let expected = [(1, "42".to_string()), (2, "7".to_string())];
+        let records = [
+            PartRecord::answered(day!(3), 1, Some("42")),
+            PartRecord::answered(day!(3), 2, Some("8")),
+        ];
+
+        let verifications = verify(day!(3), &expected, &records);
+
+        assert!(verifications[0].passed());
+        assert!(!verifications[1].passed());
+        assert_eq!(verifications[1].actual.as_deref(), Some("8"));
+    }
+
+    #[test]
+    fn fails_unsolved_parts() {
+        let expected = [(2, "7".to_string())];
+        let records = [
+            PartRecord::answered(day!(3), 1, Some("42")),
+            PartRecord::answered(day!(3), 2, None),
+        ];
+
+        let verifications = verify(day!(3), &expected, &records);
+
+        assert_eq!(verifications.len(), 1);
+        assert_eq!(verifications[0].actual, None);
+        assert!(!verifications[0].passed());
+    }
+}
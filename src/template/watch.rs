@@ -0,0 +1,214 @@
+/// Helpers of `cargo solve --watch`: polling the files of a day for changes and comparing the
+/// answers of consecutive runs.
+use std::{
+    collections::BTreeMap,
+    fs,
+    path::{Path, PathBuf},
+    time::SystemTime,
+};
+
+use crate::template::record::PartRecord;
+use crate::template::{Day, Year, bin_name, data_path};
+
+/// Modification times of all watched files.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Snapshot {
+    files: BTreeMap<PathBuf, SystemTime>,
+}
+
+impl Snapshot {
+    /// Record the modification times of the solution, the `util` modules and the data files of a day.
+    pub fn of_day(year: Year, day: Day) -> Self {
+        let mut snapshot = Snapshot::default();
+
+        snapshot.add_file(&PathBuf::from(format!(
+            "src/bin/{}.rs",
+            bin_name(year, day)
+        )));
+        snapshot.add_dir(Path::new("src/util"), &|path| {
+            path.extension().is_some_and(|ext| ext == "rs")
+        });
+
+        // NOTE: files of a day either start with its number or live in a folder named after it.
+        let prefix = day.to_string();
+        for folder in ["inputs", "examples"] {
+            snapshot.add_dir(&data_path(year, folder, ""), &|path| {
+                path.iter()
+                    .next()
+                    .and_then(|name| name.to_str())
+                    .is_some_and(|name| name.starts_with(&prefix))
+            });
+        }
+
+        snapshot
+    }
+
+    fn add_file(&mut self, path: &Path) {
+        if let Ok(modified) = fs::metadata(path).and_then(|m| m.modified()) {
+            self.files.insert(path.to_path_buf(), modified);
+        }
+    }
+
+    /// Add all files below `dir`, including sub-directories, whose path relative to `dir` matches
+    /// `filter`.
+    fn add_dir(&mut self, dir: &Path, filter: &dyn Fn(&Path) -> bool) {
+        let mut pending = vec![dir.to_path_buf()];
+
+        while let Some(current) = pending.pop() {
+            let Ok(entries) = fs::read_dir(&current) else {
+                continue;
+            };
+
+            for path in entries.flatten().map(|entry| entry.path()) {
+                if path.is_dir() {
+                    pending.push(path);
+                } else if path.strip_prefix(dir).is_ok_and(filter) {
+                    self.add_file(&path);
+                }
+            }
+        }
+    }
+
+    /// Files that were added, changed or removed since `previous`.
+    pub fn changes(&self, previous: &Snapshot) -> Vec<PathBuf> {
+        let mut changes: Vec<PathBuf> = self
+            .files
+            .iter()
+            .filter(|(path, modified)| previous.files.get(*path) != Some(modified))
+            .map(|(path, _)| path.clone())
+            .collect();
+
+        changes.extend(
+            previous
+                .files
+                .keys()
+                .filter(|path| !self.files.contains_key(*path))
+                .cloned(),
+        );
+
+        changes
+    }
+}
+
+/// Describe how the answer of each part changed between two runs, one line per part.
+pub fn diff_answers(previous: &[PartRecord], current: &[PartRecord]) -> Vec<String> {
+    let answer = |records: &[PartRecord], part: u8| {
+        records
+            .iter()
+            .find(|r| r.part == part)
+            .and_then(|r| r.answer.clone())
+    };
+
+    let format = |answer: &Option<String>| match answer.as_deref().map(str::trim) {
+        None => "✖".to_string(),
+        Some(answer) if answer.contains('\n') => format!("▼ ({} lines)", answer.lines().count()),
+        Some(answer) => answer.to_string(),
+    };
+
+    [1, 2]
+        .into_iter()
+        .filter(|part| current.iter().any(|r| r.part == *part))
+        .map(|part| {
+            let (before, now) = (answer(previous, part), answer(current, part));
+            if before == now {
+                format!("Part {part}: {} (unchanged)", format(&now))
+            } else {
+                format!("Part {part}: {} → {}", format(&before), format(&now))
+            }
+        })
+        .collect()
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::{
+        env, fs,
+        path::{Path, PathBuf},
+        process,
+        time::{Duration, SystemTime},
+    };
+
+    use super::{Snapshot, diff_answers};
+    use crate::day;
+    use crate::template::record::PartRecord;
+
+    #[test]
+    fn detects_changes() {
+        let now = SystemTime::now();
+        let later = now + Duration::from_secs(1);
+
+        let mut previous = Snapshot::default();
+        previous.files.insert("a.rs".into(), now);
+        previous.files.insert("b.rs".into(), now);
+
+        let mut current = Snapshot::default();
+        current.files.insert("a.rs".into(), now);
+        current.files.insert("c.rs".into(), later);
+
+        let mut changes = current.changes(&previous);
+        changes.sort();
+
+        assert_eq!(changes, vec![PathBuf::from("b.rs"), PathBuf::from("c.rs")]);
+        assert!(current.changes(&current).is_empty());
+    }
+
+    #[test]
+    fn watches_files_in_sub_directories() {
+        let dir = env::temp_dir().join(format!("aoc-watch-{}", process::id()));
+        for file in [
+            "a.rs",
+            "grid/b.rs",
+            "grid/notes.md",
+            "01/c.txt",
+            "01.txt",
+            "02.txt",
+        ] {
+            let path = dir.join(file);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(path, "").unwrap();
+        }
+
+        let files = |filter: &dyn Fn(&Path) -> bool| {
+            let mut snapshot = Snapshot::default();
+            snapshot.add_dir(&dir, filter);
+            let files: Vec<PathBuf> = snapshot.files.into_keys().collect();
+            files
+        };
+
+        let sources = files(&|path| path.extension().is_some_and(|ext| ext == "rs"));
+        let day = files(&|path| {
+            path.iter()
+                .next()
+                .and_then(|name| name.to_str())
+                .is_some_and(|name| name.starts_with("01"))
+        });
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(sources, vec![dir.join("a.rs"), dir.join("grid/b.rs")]);
+        assert_eq!(day, vec![dir.join("01/c.txt"), dir.join("01.txt")]);
+    }
+
+    #[test]
+    fn diffs_answers() {
+        let previous = [
+            PartRecord::answered(day!(1), 1, Some("1")),
+            PartRecord::answered(day!(1), 2, None),
+        ];
+        let current = [
+            PartRecord::answered(day!(1), 0, None),
+            PartRecord::answered(day!(1), 1, Some("1")),
+            PartRecord::answered(day!(1), 2, Some("2")),
+        ];
+
+        assert_eq!(
+            diff_answers(&previous, &current),
+            vec!["Part 1: 1 (unchanged)", "Part 2: ✖ → 2"]
+        );
+        assert_eq!(
+            diff_answers(&previous, &current[..2]),
+            vec!["Part 1: 1 (unchanged)"]
+        );
+    }
+}
@@ -0,0 +1,203 @@
+use std::error::Error;
+use std::fmt::Display;
+use std::str::FromStr;
+
+use crate::template::Day;
+
+/// Environment variable that selects the default year.
+pub const YEAR_ENV: &str = "AOC_YEAR";
+
+/// Prefix of the environment variables that override the number of days of an event, e.g. `AOC_DAYS_2025`.
+pub const DAYS_ENV_PREFIX: &str = "AOC_DAYS_";
+
+/// Year of the first Advent of Code event.
+const FIRST_YEAR: u16 = 2015;
+
+/// Starting with 2025, events have 12 instead of 25 puzzles.
+const SHORT_EVENTS_SINCE: u16 = 2025;
+
+/// A valid year of an Advent of Code event (i.e. 2015 or later).
+///
+/// # Display
+/// This value displays as a four digit number.
+///
+/// ```
+/// # use advent_of_code::template::Year;
+/// let year = Year::new(2024).unwrap();
+/// assert_eq!(year.to_string(), "2024")
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Year(u16);
+
+impl Year {
+    /// Creates a [`Year`] from the provided value if it's a year with an event,
+    /// returns [`None`] otherwise.
+    pub const fn new(year: u16) -> Option<Self> {
+        if year < FIRST_YEAR {
+            return None;
+        }
+        Some(Self(year))
+    }
+
+    /// Parses the number of a year in a const context, e.g. like `AOC_YEAR` at compile time.
+    /// Panics if `year` is not a number.
+    pub const fn parse_const(year: &str) -> u16 {
+        match u16::from_str_radix(year, 10) {
+            Ok(year) => year,
+            Err(_) => panic!("invalid year, expecting a number"),
+        }
+    }
+
+    /// Reads the default year from the `AOC_YEAR` environment variable.
+    pub fn from_env() -> Option<Self> {
+        std::env::var(YEAR_ENV).ok()?.parse().ok()
+    }
+
+    /// Parse the `--year` option from `args`, falling back to `AOC_YEAR` if it's not passed.
+    pub fn from_arguments(args: &mut pico_args::Arguments) -> Result<Self, pico_args::Error> {
+        args.opt_value_from_str("--year")?
+            .or_else(Self::from_env)
+            .ok_or(pico_args::Error::MissingOption("--year".into()))
+    }
+
+    /// Number of puzzles of the event. Can be overridden with the `AOC_DAYS_<year>` environment
+    /// variable, e.g. in `.cargo/config.toml`.
+    pub fn day_count(self) -> u8 {
+        let configured = std::env::var(format!("{DAYS_ENV_PREFIX}{self}"))
+            .ok()
+            .and_then(|x| x.parse::<Day>().ok());
+
+        match configured {
+            Some(day) => day.into_inner(),
+            None if self.0 >= SHORT_EVENTS_SINCE => 12,
+            None => 25,
+        }
+    }
+
+    /// The last day of the event.
+    pub fn last_day(self) -> Day {
+        // NOTE: `day_count` is always a valid day number.
+        Day::new(self.day_count()).unwrap()
+    }
+
+    /// Returns `true` if `day` is part of the event.
+    pub fn has_day(self, day: Day) -> bool {
+        day <= self.last_day()
+    }
+
+    /// Returns `day` if it's part of the event, an error otherwise.
+    pub fn check_day(self, day: Day) -> Result<Day, DayNotInEventError> {
+        if self.has_day(day) {
+            Ok(day)
+        } else {
+            Err(DayNotInEventError { year: self, day })
+        }
+    }
+
+    /// Converts the [`Year`] into an [`u16`].
+    pub fn into_inner(self) -> u16 {
+        self.0
+    }
+}
+
+impl Display for Year {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl PartialEq<u16> for Year {
+    fn eq(&self, other: &u16) -> bool {
+        self.0.eq(other)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl FromStr for Year {
+    type Err = YearFromStrError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let year = s.parse().map_err(|_| YearFromStrError)?;
+        Self::new(year).ok_or(YearFromStrError)
+    }
+}
+
+/// An error which can be returned when parsing a [`Year`].
+#[derive(Debug)]
+pub struct YearFromStrError;
+
+impl Error for YearFromStrError {}
+
+impl Display for YearFromStrError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("expecting a year of 2015 or later")
+    }
+}
+
+/// An error which is returned when a [`Day`] is past the last day of an event.
+#[derive(Debug)]
+pub struct DayNotInEventError {
+    year: Year,
+    day: Day,
+}
+
+impl Error for DayNotInEventError {}
+
+impl Display for DayNotInEventError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "day {} is not part of the {} event, which has {} days",
+            self.day,
+            self.year,
+            self.year.day_count()
+        )
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Creates a [`Year`] value in a const context.
+#[macro_export]
+macro_rules! year {
+    ($year:expr) => {
+        const { $crate::template::Year::new($year).expect("invalid year, expecting 2015 or later") }
+    };
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::Year;
+    use crate::day;
+
+    #[test]
+    fn parses_years() {
+        assert_eq!("2024".parse::<Year>().unwrap(), Year(2024));
+        assert!("2014".parse::<Year>().is_err());
+        assert!("24".parse::<Year>().is_err());
+        assert!("twenty".parse::<Year>().is_err());
+    }
+
+    #[test]
+    fn parses_years_in_const_context() {
+        const YEAR: u16 = Year::parse_const("2024");
+        assert_eq!(YEAR, 2024);
+    }
+
+    #[test]
+    fn knows_event_length() {
+        assert_eq!(Year(2015).day_count(), 25);
+        assert_eq!(Year(2024).day_count(), 25);
+        assert_eq!(Year(2025).day_count(), 12);
+    }
+
+    #[test]
+    fn checks_days() {
+        assert!(Year(2025).check_day(day!(12)).is_ok());
+        assert!(Year(2025).check_day(day!(13)).is_err());
+        assert!(Year(2024).check_day(day!(25)).is_ok());
+    }
+}
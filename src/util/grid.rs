@@ -1,6 +1,10 @@
+use std::error::Error;
+use std::fmt;
 use std::io::BufRead;
+use std::iter::StepBy;
+use std::ops::{Index, IndexMut};
+use std::slice::{ChunksExact, ChunksExactMut, Iter};
 
-use strum::IntoEnumIterator;
 use strum_macros::EnumIter;
 
 #[derive(EnumIter, Eq, Hash, PartialEq, Copy, Clone, Debug, Ord, PartialOrd)]
@@ -16,6 +20,26 @@ pub enum Direction {
 }
 
 impl Direction {
+    /// The four directions that do not move diagonally, clockwise from north.
+    pub const ORTHOGONAL: [Direction; 4] = [
+        Direction::North,
+        Direction::East,
+        Direction::South,
+        Direction::West,
+    ];
+
+    /// All eight directions, clockwise from north.
+    pub const ALL: [Direction; 8] = [
+        Direction::North,
+        Direction::NorthEast,
+        Direction::East,
+        Direction::SouthEast,
+        Direction::South,
+        Direction::SouthWest,
+        Direction::West,
+        Direction::NorthWest,
+    ];
+
     pub fn rotate_right(&self) -> Direction {
         match self {
             Direction::North => Direction::East,
@@ -43,47 +67,88 @@ impl Direction {
     }
 }
 
-pub struct ElementIterator<'a> {
-    grid: &'a Grid,
-    x: usize,
-    y: usize,
-    element: u8,
+/// Offsets of the four orthogonal neighbors: north, east, south and west.
+const ORTHOGONAL: [(isize, isize); 4] = [(0, -1), (1, 0), (0, 1), (-1, 0)];
+
+/// Offsets of the four diagonal neighbors, clockwise from north-east.
+const DIAGONAL: [(isize, isize); 4] = [(1, -1), (1, 1), (-1, 1), (-1, -1)];
+
+/// Offsets of all eight neighbors, in the order of [`Direction`].
+const MOORE: [(isize, isize); 8] = [
+    (0, -1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+    (0, 1),
+    (-1, 1),
+    (-1, 0),
+    (-1, -1),
+];
+
+/// The cells that count as neighbors of a cell.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Neighborhood {
+    /// The four cells that share an edge, also known as the von Neumann neighborhood.
+    Orthogonal,
+    /// The four cells that only share a corner.
+    Diagonal,
+    /// All eight surrounding cells, also known as the Moore neighborhood.
+    Moore,
+    /// Cells at the given `(dx, dy)` offsets, e.g. the moves of a knight.
+    Custom(&'static [(isize, isize)]),
+}
+
+impl Neighborhood {
+    /// The `(dx, dy)` offsets of the neighbors.
+    pub fn offsets(self) -> &'static [(isize, isize)] {
+        match self {
+            Neighborhood::Orthogonal => &ORTHOGONAL,
+            Neighborhood::Diagonal => &DIAGONAL,
+            Neighborhood::Moore => &MOORE,
+            Neighborhood::Custom(offsets) => offsets,
+        }
+    }
+}
+
+pub struct ElementIterator<'a, T = u8> {
+    grid: &'a Grid<T>,
+    index: usize,
+    element: T,
 }
 
-impl<'a> Iterator for ElementIterator<'a> {
+impl<'a, T: PartialEq> Iterator for ElementIterator<'a, T> {
     type Item = (usize, usize);
 
     fn next(&mut self) -> Option<Self::Item> {
-        for j in self.y..self.grid.height {
-            for i in self.x..self.grid.width {
-                if self.grid.elems[j][i] == self.element {
-                    self.x = i + 1;
-                    self.y = j;
+        let offset = self.grid.cells[self.index..]
+            .iter()
+            .position(|cell| *cell == self.element)?;
 
-                    return Some((i, j));
-                }
-            }
-            self.x = 0;
-        }
+        let index = self.index + offset;
+        self.index = index + 1;
 
-        None
+        Some(self.grid.coords(index))
     }
 }
 
-pub struct AdjacentIterator<'a> {
-    grid: &'a Grid,
+pub struct AdjacentIterator<'a, T = u8> {
+    grid: &'a Grid<T>,
     x: usize,
     y: usize,
-    directions: std::vec::IntoIter<Direction>,
+    offsets: Iter<'static, (isize, isize)>,
 }
 
-impl<'a> Iterator for AdjacentIterator<'a> {
+impl<'a, T> Iterator for AdjacentIterator<'a, T> {
     type Item = (usize, usize);
 
     fn next(&mut self) -> Option<Self::Item> {
-        for direction in &mut self.directions {
-            if let Some(coords) = self.grid.get_coords(direction, self.x, self.y) {
-                return Some(coords);
+        for (dx, dy) in &mut self.offsets {
+            if let Some(x) = self.x.checked_add_signed(*dx)
+                && let Some(y) = self.y.checked_add_signed(*dy)
+                && x < self.grid.width
+                && y < self.grid.height
+            {
+                return Some((x, y));
             }
         }
 
@@ -91,18 +156,18 @@ impl<'a> Iterator for AdjacentIterator<'a> {
     }
 }
 
-pub struct AdjacentElementIterator<'a> {
-    grid: &'a Grid,
-    element: u8,
-    adjacent: AdjacentIterator<'a>,
+pub struct AdjacentElementIterator<'a, T = u8> {
+    grid: &'a Grid<T>,
+    element: T,
+    adjacent: AdjacentIterator<'a, T>,
 }
 
-impl<'a> Iterator for AdjacentElementIterator<'a> {
+impl<'a, T: PartialEq> Iterator for AdjacentElementIterator<'a, T> {
     type Item = (usize, usize);
 
     fn next(&mut self) -> Option<Self::Item> {
         for coords in &mut self.adjacent {
-            if self.grid.get(coords) == self.element {
+            if self.grid[coords] == self.element {
                 return Some(coords);
             }
         }
@@ -111,11 +176,64 @@ impl<'a> Iterator for AdjacentElementIterator<'a> {
     }
 }
 
+/// How a cell is printed, one character per cell.
+pub trait ToChar {
+    fn to_char(&self) -> char;
+}
+
+impl ToChar for u8 {
+    fn to_char(&self) -> char {
+        char::from(*self)
+    }
+}
+
+impl ToChar for char {
+    fn to_char(&self) -> char {
+        *self
+    }
+}
+
+/// Prints like the letters some puzzles draw, see [`ocr`](crate::util::ocr).
+impl ToChar for bool {
+    fn to_char(&self) -> char {
+        if *self { '#' } else { '.' }
+    }
+}
+
+/// A cell of the input that could not be parsed.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseError {
+    /// Row of the cell, starting at 1 like in an editor.
+    pub row: usize,
+    /// Column of the cell, starting at 1 like in an editor.
+    pub column: usize,
+    pub message: String,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "invalid cell at row {}, column {}: {}",
+            self.row, self.column, self.message
+        )
+    }
+}
+
+impl Error for ParseError {}
+
+/// A rectangular grid of cells, which are raw bytes of the input by default.
+///
+/// Cells are stored row after row in a single allocation: the cell at `(x, y)` lives at index
+/// `y * width + x`, so rows are slices and columns are strided iterators over the same storage.
+/// Code that used the nested `elems` rows reads `grid.row(y)[x]` instead of `grid.elems[y][x]`,
+/// writes through [`row_mut`](Self::row_mut) and loops over [`rows`](Self::rows) or
+/// [`rows_mut`](Self::rows_mut).
 #[derive(Clone, Debug)]
-pub struct Grid {
+pub struct Grid<T = u8> {
     pub width: usize,
     pub height: usize,
-    pub elems: Vec<Vec<u8>>,
+    cells: Vec<T>,
 }
 
 impl Grid {
@@ -123,13 +241,109 @@ impl Grid {
         Self::new(input.as_bytes().lines().flatten().collect::<Vec<_>>())
     }
 
+    /// A grid of the bytes of `raw`. Rows are expected to have the same length, use
+    /// [`parse_with`](Grid::parse_with) to get an error for input with rows of different lengths.
     pub fn new(raw: Vec<String>) -> Self {
         Self {
-            width: raw[0].len(),
+            width: raw.first().map_or(0, String::len),
             height: raw.len(),
-            elems: raw.iter().map(|s| s.bytes().collect()).collect(),
+            cells: raw.iter().flat_map(|s| s.bytes()).collect(),
         }
     }
+}
+
+impl<T> Grid<T> {
+    /// Parse every byte of the input into a cell, e.g. with `Grid::parse_with(input, Tile::try_from)`.
+    pub fn parse_with<E: fmt::Display>(
+        input: &str,
+        mut parse: impl FnMut(u8) -> Result<T, E>,
+    ) -> Result<Self, ParseError> {
+        let lines: Vec<&str> = input.lines().collect();
+        let width = lines.first().map_or(0, |line| line.len());
+
+        let mut cells = Vec::with_capacity(width * lines.len());
+        for (y, line) in lines.iter().enumerate() {
+            if line.len() != width {
+                return Err(ParseError {
+                    row: y + 1,
+                    column: line.len().min(width) + 1,
+                    message: format!("expected {width} cells, found {}", line.len()),
+                });
+            }
+
+            for (x, byte) in line.bytes().enumerate() {
+                cells.push(parse(byte).map_err(|e| ParseError {
+                    row: y + 1,
+                    column: x + 1,
+                    message: e.to_string(),
+                })?);
+            }
+        }
+
+        Ok(Self {
+            width,
+            height: lines.len(),
+            cells,
+        })
+    }
+
+    /// A grid of `width` by `height` cells that all hold `value`, e.g. a map of visited cells.
+    pub fn filled(width: usize, height: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            width,
+            height,
+            cells: vec![value; width * height],
+        }
+    }
+
+    /// Index of the cell at `(x, y)` in [`cells`](Self::cells).
+    ///
+    /// Panics if `x` is out of bounds, which would otherwise wrap around to the next row.
+    pub fn index(&self, (x, y): (usize, usize)) -> usize {
+        assert!(x < self.width, "column {x} is out of bounds");
+        y * self.width + x
+    }
+
+    /// Coordinates of the cell at `index` in [`cells`](Self::cells).
+    pub fn coords(&self, index: usize) -> (usize, usize) {
+        (index % self.width, index / self.width)
+    }
+
+    /// All cells, row after row.
+    pub fn cells(&self) -> &[T] {
+        &self.cells
+    }
+
+    pub fn cells_mut(&mut self) -> &mut [T] {
+        &mut self.cells
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn row_mut(&mut self, y: usize) -> &mut [T] {
+        &mut self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    /// All rows, from top to bottom. Iterates like the former `elems` field did.
+    pub fn rows(&self) -> ChunksExact<'_, T> {
+        // NOTE: `chunks_exact` does not accept a chunk size of 0, empty grids have no rows anyway.
+        self.cells.chunks_exact(self.width.max(1))
+    }
+
+    pub fn rows_mut(&mut self) -> ChunksExactMut<'_, T> {
+        self.cells.chunks_exact_mut(self.width.max(1))
+    }
+
+    /// The cells of column `x`, from top to bottom.
+    pub fn column(&self, x: usize) -> StepBy<Iter<'_, T>> {
+        assert!(x < self.width, "column {x} is out of bounds");
+        self.cells[x..].iter().step_by(self.width)
+    }
 
     pub fn get_coords2(
         &self,
@@ -200,44 +414,125 @@ impl Grid {
         }
     }
 
-    pub fn find(&self, element: u8) -> ElementIterator<'_> {
+    pub fn find(&self, element: T) -> ElementIterator<'_, T>
+    where
+        T: PartialEq,
+    {
         ElementIterator {
             grid: self,
-            x: 0,
-            y: 0,
+            index: 0,
             element,
         }
     }
 
-    pub fn adjacent(&self, x: usize, y: usize) -> AdjacentIterator<'_> {
+    /// All eight neighbors of a cell, including diagonal ones. Use [`adjacent_in`](Self::adjacent_in)
+    /// for puzzles that only move in four directions.
+    pub fn adjacent(&self, x: usize, y: usize) -> AdjacentIterator<'_, T> {
+        self.adjacent_in(x, y, Neighborhood::Moore)
+    }
+
+    /// The neighbors of a cell in `neighborhood` that are inside of the grid.
+    pub fn adjacent_in(
+        &self,
+        x: usize,
+        y: usize,
+        neighborhood: Neighborhood,
+    ) -> AdjacentIterator<'_, T> {
         AdjacentIterator {
             grid: self,
             x,
             y,
-            directions: Direction::iter().collect::<Vec<_>>().into_iter(),
+            offsets: neighborhood.offsets().iter(),
         }
     }
 
-    pub fn adjacent_element(&self, x: usize, y: usize, element: u8) -> AdjacentElementIterator<'_> {
+    /// All eight neighbors of a cell that hold `element`, see [`adjacent`](Self::adjacent).
+    pub fn adjacent_element(&self, x: usize, y: usize, element: T) -> AdjacentElementIterator<'_, T>
+    where
+        T: PartialEq,
+    {
+        self.adjacent_element_in(x, y, element, Neighborhood::Moore)
+    }
+
+    /// The neighbors of a cell in `neighborhood` that hold `element`.
+    pub fn adjacent_element_in(
+        &self,
+        x: usize,
+        y: usize,
+        element: T,
+        neighborhood: Neighborhood,
+    ) -> AdjacentElementIterator<'_, T>
+    where
+        T: PartialEq,
+    {
         AdjacentElementIterator {
-            adjacent: self.adjacent(x, y),
+            adjacent: self.adjacent_in(x, y, neighborhood),
             grid: self,
             element,
         }
     }
 
-    pub fn get(&self, (x, y): (usize, usize)) -> u8 {
-        self.elems[y][x]
+    /// Number of neighbors of a cell in `neighborhood` that hold `element`.
+    pub fn count_adjacent(
+        &self,
+        x: usize,
+        y: usize,
+        element: T,
+        neighborhood: Neighborhood,
+    ) -> usize
+    where
+        T: PartialEq,
+    {
+        self.adjacent_in(x, y, neighborhood)
+            .filter(|coords| self[*coords] == element)
+            .count()
     }
 
-    pub fn set(&mut self, (x, y): (usize, usize), value: u8) {
-        self.elems[y][x] = value;
+    pub fn get(&self, (x, y): (usize, usize)) -> T
+    where
+        T: Copy,
+    {
+        self[(x, y)]
     }
 
-    pub fn print(&self) {
-        for j in 0..self.height {
-            println!("{}", String::from_utf8(self.elems[j].clone()).unwrap());
-        }
+    pub fn set(&mut self, (x, y): (usize, usize), value: T) {
+        self[(x, y)] = value;
+    }
+
+    /// Render the grid with one character per cell, e.g. to print a grid of counters.
+    pub fn render(&self, to_char: impl Fn(&T) -> char) -> String {
+        self.rows()
+            .map(|row| row.iter().map(&to_char).collect::<String>())
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    pub fn print(&self)
+    where
+        T: ToChar,
+    {
+        println!("{self}");
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (x, y): (usize, usize)) -> &T {
+        &self.cells[self.index((x, y))]
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, (x, y): (usize, usize)) -> &mut T {
+        let index = self.index((x, y));
+        &mut self.cells[index]
+    }
+}
+
+impl<T: ToChar> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.render(T::to_char))
     }
 }
 
@@ -392,4 +687,158 @@ def
         // THEN
         assert_eq!(set.len(), 0);
     }
+
+    #[test]
+    fn test_adjacent_in_should_select_neighborhood() {
+        // GIVEN
+        let grid = Grid::parse_input("abc\ndef\nghi\n");
+        const KNIGHT: &[(isize, isize)] = &[(1, 2), (2, 1), (-1, -2), (-2, -1)];
+
+        // WHEN
+        let neighbors = |neighborhood| {
+            grid.adjacent_in(1, 1, neighborhood)
+                .map(|coords| grid.get(coords))
+                .collect::<Vec<_>>()
+        };
+
+        // THEN
+        assert_eq!(neighbors(Neighborhood::Orthogonal), b"bfhd");
+        assert_eq!(neighbors(Neighborhood::Diagonal), b"ciga");
+        assert_eq!(neighbors(Neighborhood::Moore), b"bcfihgda");
+        assert_eq!(
+            grid.adjacent_in(0, 0, Neighborhood::Custom(KNIGHT))
+                .collect::<Vec<_>>(),
+            vec![(1, 2), (2, 1)]
+        );
+    }
+
+    #[test]
+    fn test_count_adjacent_should_only_count_neighborhood() {
+        // GIVEN
+        let grid = Grid::parse_input("#.#\n.#.\n#.#\n");
+
+        // WHEN
+        let orthogonal = grid.count_adjacent(1, 1, b'#', Neighborhood::Orthogonal);
+        let diagonal = grid.count_adjacent(1, 1, b'#', Neighborhood::Diagonal);
+
+        // THEN
+        assert_eq!(orthogonal, 0);
+        assert_eq!(diagonal, 4);
+        assert_eq!(
+            grid.adjacent_element_in(1, 0, b'#', Neighborhood::Orthogonal)
+                .count(),
+            3
+        );
+    }
+
+    #[test]
+    fn test_rows_and_columns_should_slice_storage() {
+        // GIVEN
+        let mut grid = Grid::parse_input("abc\ndef\n");
+
+        // WHEN
+        grid.row_mut(1)[2] = b'x';
+
+        // THEN
+        assert_eq!(grid.row(0), b"abc");
+        assert_eq!(grid.rows().collect::<Vec<_>>(), vec![b"abc", b"dex"]);
+        assert_eq!(grid.column(2).copied().collect::<Vec<_>>(), b"cx");
+        assert_eq!(grid.index((2, 1)), 5);
+        assert_eq!(grid.coords(5), (2, 1));
+        assert_eq!(grid.cells()[5], grid[(2, 1)]);
+        for row in grid.rows_mut() {
+            row[0] = b'_';
+        }
+        assert_eq!(grid.rows().collect::<Vec<_>>(), vec![b"_bc", b"_ex"]);
+    }
+
+    #[test]
+    #[should_panic(expected = "column 3 is out of bounds")]
+    fn test_index_should_not_wrap_to_next_row() {
+        // GIVEN
+        let grid = Grid::parse_input("abc\ndef\n");
+
+        // WHEN
+        let _ = grid[(3, 0)];
+    }
+
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    enum Tile {
+        Empty,
+        Wall,
+    }
+
+    impl TryFrom<u8> for Tile {
+        type Error = String;
+
+        fn try_from(value: u8) -> Result<Self, Self::Error> {
+            match value {
+                b'.' => Ok(Tile::Empty),
+                b'#' => Ok(Tile::Wall),
+                _ => Err(format!("unknown tile `{}`", char::from(value))),
+            }
+        }
+    }
+
+    #[test]
+    fn test_parse_with_should_parse_typed_cells() {
+        // GIVEN
+        let input = "#.\n.#\n";
+
+        // WHEN
+        let grid = Grid::parse_with(input, Tile::try_from).unwrap();
+
+        // THEN
+        assert_eq!((grid.width, grid.height), (2, 2));
+        assert_eq!(grid.get((0, 0)), Tile::Wall);
+        assert_eq!(
+            grid.find(Tile::Wall).collect::<Vec<_>>(),
+            vec![(0, 0), (1, 1)]
+        );
+        assert_eq!(grid.adjacent_element(0, 0, Tile::Empty).count(), 2);
+    }
+
+    #[test]
+    fn test_parse_with_should_report_position_of_invalid_cells() {
+        // GIVEN
+        let input = "#.\n.x\n";
+
+        // WHEN
+        let error = Grid::parse_with(input, Tile::try_from).unwrap_err();
+
+        // THEN
+        assert_eq!(
+            error.to_string(),
+            "invalid cell at row 2, column 2: unknown tile `x`"
+        );
+        assert_eq!(
+            Grid::parse_with("##\n#\n", Tile::try_from).unwrap_err(),
+            ParseError {
+                row: 2,
+                column: 2,
+                message: "expected 2 cells, found 1".into()
+            }
+        );
+    }
+
+    #[test]
+    fn test_display_should_print_any_cell_type() {
+        // GIVEN
+        let mut visited = Grid::filled(3, 2, false);
+        let mut counters = Grid::parse_with("12\n34", |b| {
+            char::from(b).to_digit(10).ok_or("not a digit")
+        })
+        .unwrap();
+
+        // WHEN
+        visited.set((1, 0), true);
+        counters.set((0, 0), 10);
+
+        // THEN
+        assert_eq!(visited.to_string(), ".#.\n...");
+        assert_eq!(
+            counters.render(|n| if *n > 9 { '+' } else { '0' }),
+            "+0\n00"
+        );
+    }
 }
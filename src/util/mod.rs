@@ -1,2 +1,5 @@
 pub mod grid;
+pub mod ocr;
+pub mod regions;
+pub mod search;
 pub mod union_find;
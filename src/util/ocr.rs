@@ -0,0 +1,279 @@
+//! Recognition of capital letters drawn in `#` and `.`, as some puzzles render their answer.
+//!
+//! Supports the two fonts of Advent of Code: letters that are 6 pixels high (usually 4 wide)
+//! and letters that are 10 pixels high (usually 6 wide). Letters are separated by empty columns,
+//! except for letters as wide as the spacing of the font, which touch the next one.
+
+use crate::util::grid::{Grid, ToChar};
+
+/// Letters of the font that is 6 pixels high.
+const FONT_6: [(char, &str); 18] = [
+    ('A', ".##.\n#..#\n#..#\n####\n#..#\n#..#"),
+    ('B', "###.\n#..#\n###.\n#..#\n#..#\n###."),
+    ('C', ".##.\n#..#\n#...\n#...\n#..#\n.##."),
+    ('E', "####\n#...\n###.\n#...\n#...\n####"),
+    ('F', "####\n#...\n###.\n#...\n#...\n#..."),
+    ('G', ".##.\n#..#\n#...\n#.##\n#..#\n.###"),
+    ('H', "#..#\n#..#\n####\n#..#\n#..#\n#..#"),
+    ('I', "###\n.#.\n.#.\n.#.\n.#.\n###"),
+    ('J', "..##\n...#\n...#\n...#\n#..#\n.##."),
+    ('K', "#..#\n#.#.\n##..\n#.#.\n#.#.\n#..#"),
+    ('L', "#...\n#...\n#...\n#...\n#...\n####"),
+    ('O', ".##.\n#..#\n#..#\n#..#\n#..#\n.##."),
+    ('P', "###.\n#..#\n#..#\n###.\n#...\n#..."),
+    ('R', "###.\n#..#\n#..#\n###.\n#.#.\n#..#"),
+    ('S', ".###\n#...\n#...\n.##.\n...#\n###."),
+    ('U', "#..#\n#..#\n#..#\n#..#\n#..#\n.##."),
+    ('Y', "#...#\n#...#\n.#.#.\n..#..\n..#..\n..#.."),
+    ('Z', "####\n...#\n..#.\n.#..\n#...\n####"),
+];
+
+/// Letters of the font that is 10 pixels high.
+const FONT_10: [(char, &str); 15] = [
+    (
+        'A',
+        "..##..\n.#..#.\n#....#\n#....#\n#....#\n######\n#....#\n#....#\n#....#\n#....#",
+    ),
+    (
+        'B',
+        "#####.\n#....#\n#....#\n#....#\n#####.\n#....#\n#....#\n#....#\n#....#\n#####.",
+    ),
+    (
+        'C',
+        ".####.\n#....#\n#.....\n#.....\n#.....\n#.....\n#.....\n#.....\n#....#\n.####.",
+    ),
+    (
+        'E',
+        "######\n#.....\n#.....\n#.....\n#####.\n#.....\n#.....\n#.....\n#.....\n######",
+    ),
+    (
+        'F',
+        "######\n#.....\n#.....\n#.....\n#####.\n#.....\n#.....\n#.....\n#.....\n#.....",
+    ),
+    (
+        'G',
+        ".####.\n#....#\n#.....\n#.....\n#.....\n#..###\n#....#\n#....#\n#...##\n.###.#",
+    ),
+    (
+        'H',
+        "#....#\n#....#\n#....#\n#....#\n######\n#....#\n#....#\n#....#\n#....#\n#....#",
+    ),
+    (
+        'J',
+        "...###\n....#.\n....#.\n....#.\n....#.\n....#.\n....#.\n#...#.\n#...#.\n.###..",
+    ),
+    (
+        'K',
+        "#....#\n#...#.\n#..#..\n#.#...\n##....\n##....\n#.#...\n#..#..\n#...#.\n#....#",
+    ),
+    (
+        'L',
+        "#.....\n#.....\n#.....\n#.....\n#.....\n#.....\n#.....\n#.....\n#.....\n######",
+    ),
+    (
+        'N',
+        "#....#\n##...#\n##...#\n#.#..#\n#.#..#\n#..#.#\n#..#.#\n#...##\n#...##\n#....#",
+    ),
+    (
+        'P',
+        "#####.\n#....#\n#....#\n#....#\n#####.\n#.....\n#.....\n#.....\n#.....\n#.....",
+    ),
+    (
+        'R',
+        "#####.\n#....#\n#....#\n#....#\n#####.\n#..#..\n#...#.\n#...#.\n#....#\n#....#",
+    ),
+    (
+        'X',
+        "#....#\n#....#\n.#..#.\n.#..#.\n..##..\n..##..\n.#..#.\n.#..#.\n#....#\n#....#",
+    ),
+    (
+        'Z',
+        "######\n.....#\n.....#\n....#.\n...#..\n..#...\n.#....\n#.....\n#.....\n######",
+    ),
+];
+
+/// Recognize the letters drawn in `text`. Lit pixels are `#` or `█`, any other character is dark.
+/// Returns [`None`] if the drawing is not made up of known letters.
+pub fn recognize(text: &str) -> Option<String> {
+    let mut rows: Vec<Vec<bool>> = text
+        .lines()
+        .map(|line| line.chars().map(|c| c == '#' || c == '█').collect())
+        .collect();
+
+    // NOTE: empty rows around the letters are not part of the font.
+    while rows.last().is_some_and(|row| !row.contains(&true)) {
+        rows.pop();
+    }
+    let first_lit = rows.iter().position(|row| row.contains(&true))?;
+    rows.drain(..first_lit);
+
+    let font: &[(char, &str)] = match rows.len() {
+        6 => &FONT_6,
+        10 => &FONT_10,
+        _ => return None,
+    };
+
+    let width = rows.iter().map(Vec::len).max()?;
+    let is_lit = |x: usize, y: usize| rows[y].get(x).copied().unwrap_or(false);
+    let is_empty_column = |x: usize| (0..rows.len()).all(|y| !is_lit(x, y));
+
+    let glyph = |start: usize, end: usize| {
+        (0..rows.len())
+            .map(|y| {
+                (start..end)
+                    .map(|x| if is_lit(x, y) { '#' } else { '.' })
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    };
+
+    let mut letters = String::new();
+    let mut x = 0;
+
+    while x < width {
+        if is_empty_column(x) {
+            x += 1;
+            continue;
+        }
+
+        let mut end = x;
+        while end < width && !is_empty_column(end) {
+            end += 1;
+        }
+
+        // NOTE: letters as wide as the spacing of the font touch the next letter, e.g. like `Y`
+        // on the screen of 2016 day 8. The widest letter that matches is taken first.
+        while x < end {
+            let (letter, letter_width) = font
+                .iter()
+                .filter_map(|(letter, pixels)| {
+                    let letter_width = pixels.lines().next()?.len();
+                    (x + letter_width <= end && glyph(x, x + letter_width) == *pixels)
+                        .then_some((*letter, letter_width))
+                })
+                .max_by_key(|(_, letter_width)| *letter_width)?;
+
+            letters.push(letter);
+            x += letter_width;
+        }
+    }
+
+    Some(letters)
+}
+
+/// Recognize the letters drawn on a grid, see [`recognize`].
+pub fn recognize_grid<T: ToChar>(grid: &Grid<T>) -> Option<String> {
+    recognize(&grid.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Draw `letters` like a puzzle would, with an empty column between them.
+    fn draw(font: &[(char, &str)], letters: &str) -> String {
+        let glyphs: Vec<Vec<&str>> = letters
+            .chars()
+            .map(|letter| {
+                let (_, pixels) = font.iter().find(|(l, _)| *l == letter).unwrap();
+                pixels.lines().collect()
+            })
+            .collect();
+
+        (0..glyphs[0].len())
+            .map(|y| {
+                glyphs
+                    .iter()
+                    .map(|glyph| format!("{}.", glyph[y]))
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    #[test]
+    fn test_recognize_should_read_small_letters() {
+        // GIVEN
+        let text = draw(&FONT_6, "ABCEFGHIJKLOPRSUYZ");
+
+        // WHEN
+        let letters = recognize(&text);
+
+        // THEN
+        assert_eq!(letters, Some("ABCEFGHIJKLOPRSUYZ".to_string()));
+    }
+
+    #[test]
+    fn test_recognize_should_read_large_letters() {
+        // GIVEN
+        let text = format!(
+            "\n{}\n",
+            draw(&FONT_10, "ABCEFGHJKLNPRXZ").replace('.', " ")
+        );
+
+        // WHEN
+        let letters = recognize(&text);
+
+        // THEN
+        assert_eq!(letters, Some("ABCEFGHJKLNPRXZ".to_string()));
+    }
+
+    #[test]
+    fn test_recognize_should_read_a_small_screen() {
+        // GIVEN the screen of 2016 day 8, where letters are 5 pixels apart and `Y` touches `F`
+        let text = "\
+.##..####.#....####.#.....##..#...#####..##...###.
+#..#.#....#....#....#....#..#.#...##....#..#.#....
+#....###..#....###..#....#..#..#.#.###..#....#....
+#....#....#....#....#....#..#...#..#....#.....##..
+#..#.#....#....#....#....#..#...#..#....#..#....#.
+.##..#....####.####.####..##....#..#.....##..###..";
+
+        // WHEN
+        let letters = recognize(text);
+
+        // THEN
+        assert_eq!(letters, Some("CFLELOYFCS".to_string()));
+    }
+
+    #[test]
+    fn test_recognize_should_read_a_large_message() {
+        // GIVEN the message of 2018 day 10, cropped to the lit points
+        let text = "\
+#####...#.......######..######..#....#..#####.....##....#....#
+#....#..#.......#............#..##...#..#....#...#..#...##...#
+#....#..#.......#............#..##...#..#....#..#....#..##...#
+#....#..#.......#...........#...#.#..#..#....#..#....#..#.#..#
+#####...#.......#####......#....#.#..#..#####...#....#..#.#..#
+#..#....#.......#.........#.....#..#.#..#..#....######..#..#.#
+#...#...#.......#........#......#..#.#..#...#...#....#..#..#.#
+#...#...#.......#.......#.......#...##..#...#...#....#..#...##
+#....#..#.......#.......#.......#...##..#....#..#....#..#...##
+#....#..######..######..######..#....#..#....#..#....#..#....#";
+
+        // WHEN
+        let letters = recognize(text);
+
+        // THEN
+        assert_eq!(letters, Some("RLEZNRAN".to_string()));
+    }
+
+    #[test]
+    fn test_recognize_grid_should_read_grids() {
+        // GIVEN
+        let grid = Grid::parse_input(&draw(&FONT_6, "HI"));
+
+        // WHEN
+        let letters = recognize_grid(&grid);
+
+        // THEN
+        assert_eq!(letters, Some("HI".to_string()));
+    }
+
+    #[test]
+    fn test_recognize_should_reject_unknown_drawings() {
+        assert_eq!(recognize("#..#\n####"), None);
+        assert_eq!(recognize(&draw(&FONT_6, "A").replace(".##.", "####")), None);
+    }
+}
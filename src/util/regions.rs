@@ -0,0 +1,207 @@
+//! Labelling of connected regions of a grid, e.g. the garden plots of a map.
+//!
+//! Cells are connected through a [`Neighborhood`], usually [`Neighborhood::Orthogonal`] or
+//! [`Neighborhood::Moore`]. Perimeters and sides are always measured along the edges of cells.
+
+use crate::util::grid::{Direction, Grid, Neighborhood};
+use crate::util::search::Coords;
+use crate::util::union_find::UnionFind;
+
+/// A connected region of cells.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Region {
+    pub label: usize,
+    /// Number of cells of the region.
+    pub area: usize,
+    /// Number of cell edges between the region and the rest of the grid, including its border.
+    pub perimeter: usize,
+    /// Number of straight sides of the region's outline, including the outlines of its holes.
+    pub sides: usize,
+    /// Top left corner of the bounding box.
+    pub min: Coords,
+    /// Bottom right corner of the bounding box.
+    pub max: Coords,
+}
+
+/// Regions of a grid and the region of every cell.
+#[derive(Clone, Debug)]
+pub struct Regions {
+    /// Label of the region of each cell, [`None`] for cells outside of all regions.
+    pub labels: Grid<Option<usize>>,
+    /// All regions, indexed by their label in order of their first cell.
+    pub regions: Vec<Region>,
+}
+
+impl Regions {
+    pub fn region_at(&self, coords: Coords) -> Option<&Region> {
+        self.labels[coords].map(|label| &self.regions[label])
+    }
+}
+
+/// Label the connected regions of the cells that hold `value`.
+pub fn regions_of<T: PartialEq>(grid: &Grid<T>, value: T, neighborhood: Neighborhood) -> Regions {
+    regions(grid, neighborhood, |cell| *cell == value)
+}
+
+/// Label the connected regions of the cells that satisfy `is_member`.
+pub fn regions<T>(
+    grid: &Grid<T>,
+    neighborhood: Neighborhood,
+    is_member: impl Fn(&T) -> bool,
+) -> Regions {
+    let mut members = Grid::filled(grid.width, grid.height, false);
+    for (cell, member) in grid.cells().iter().zip(members.cells_mut()) {
+        *member = is_member(cell);
+    }
+    let member_indices: Vec<usize> = (0..grid.cells().len())
+        .filter(|index| members.cells()[*index])
+        .collect();
+
+    let mut union_find = UnionFind::new(grid.cells().len());
+    for &index in &member_indices {
+        let (x, y) = grid.coords(index);
+        for neighbor in members.adjacent_element_in(x, y, true, neighborhood) {
+            union_find.union(index, grid.index(neighbor));
+        }
+    }
+
+    // NOTE: roots are arbitrary cells of their region, labels follow the order of first cells.
+    let mut root_labels = vec![None; grid.cells().len()];
+    let mut labels = Grid::filled(grid.width, grid.height, None);
+    let mut regions: Vec<Region> = vec![];
+
+    for &index in &member_indices {
+        let root = union_find.find(index);
+        let coords = grid.coords(index);
+
+        let label = *root_labels[root].get_or_insert_with(|| {
+            regions.push(Region {
+                label: regions.len(),
+                area: 0,
+                perimeter: 0,
+                sides: 0,
+                min: coords,
+                max: coords,
+            });
+            regions.len() - 1
+        });
+        labels[coords] = Some(label);
+
+        let region = &mut regions[label];
+        region.area += 1;
+        region.min = (region.min.0.min(coords.0), region.min.1.min(coords.1));
+        region.max = (region.max.0.max(coords.0), region.max.1.max(coords.1));
+    }
+
+    for &index in &member_indices {
+        let coords = grid.coords(index);
+        let label = labels[coords];
+        let same = |direction: Direction| {
+            grid.get_coords(direction, coords.0, coords.1)
+                .is_some_and(|neighbor| labels[neighbor] == label)
+        };
+
+        let region = &mut regions[label.unwrap()];
+        region.perimeter += Direction::ORTHOGONAL
+            .into_iter()
+            .filter(|direction| !same(*direction))
+            .count();
+
+        // NOTE: a region has as many sides as corners, counted at the corners of its cells.
+        for direction in Direction::ORTHOGONAL {
+            let (side, next_side) = (direction, direction.rotate_right());
+            let diagonal = match direction {
+                Direction::North => Direction::NorthEast,
+                Direction::East => Direction::SouthEast,
+                Direction::South => Direction::SouthWest,
+                _ => Direction::NorthWest,
+            };
+
+            let is_outer = !same(side) && !same(next_side);
+            let is_inner = same(side) && same(next_side) && !same(diagonal);
+            if is_outer || is_inner {
+                region.sides += 1;
+            }
+        }
+    }
+
+    Regions { labels, regions }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_regions_of_should_measure_regions() {
+        // GIVEN
+        let grid = Grid::parse_input(
+            r#"AAAA
+BBCD
+BBCC
+EEEC
+"#,
+        );
+
+        // WHEN
+        let a = regions_of(&grid, b'A', Neighborhood::Orthogonal);
+        let c = regions_of(&grid, b'C', Neighborhood::Orthogonal);
+
+        // THEN
+        assert_eq!(a.regions.len(), 1);
+        assert_eq!((a.regions[0].area, a.regions[0].perimeter), (4, 10));
+        assert_eq!(a.regions[0].sides, 4);
+        assert_eq!(c.regions.len(), 1);
+        assert_eq!((c.regions[0].area, c.regions[0].perimeter), (4, 10));
+        assert_eq!(c.regions[0].sides, 8);
+        assert_eq!((c.regions[0].min, c.regions[0].max), ((2, 1), (3, 3)));
+    }
+
+    #[test]
+    fn test_regions_should_count_sides_of_holes() {
+        // GIVEN
+        let grid = Grid::parse_input(
+            r#"AAAAAA
+AAABBA
+AAABBA
+ABBAAA
+ABBAAA
+AAAAAA
+"#,
+        );
+
+        // WHEN
+        let regions = regions(&grid, Neighborhood::Orthogonal, |cell| *cell == b'A');
+
+        // THEN
+        assert_eq!(regions.regions.len(), 1);
+        assert_eq!(regions.regions[0].area, 28);
+        assert_eq!(regions.regions[0].sides, 12);
+        assert_eq!(regions.labels[(3, 1)], None);
+    }
+
+    #[test]
+    fn test_regions_should_use_connectivity_of_neighborhood() {
+        // GIVEN
+        let grid = Grid::parse_input(
+            r#"#..
+.#.
+..#
+#..
+"#,
+        );
+
+        // WHEN
+        let orthogonal = regions_of(&grid, b'#', Neighborhood::Orthogonal);
+        let moore = regions_of(&grid, b'#', Neighborhood::Moore);
+
+        // THEN
+        assert_eq!(orthogonal.regions.len(), 4);
+        assert_eq!(moore.regions.len(), 2);
+        assert_eq!(moore.regions[0].area, 3);
+        assert_eq!(moore.regions[0].perimeter, 12);
+        assert_eq!(moore.region_at((2, 2)), Some(&moore.regions[0]));
+        assert_eq!(moore.region_at((0, 3)).map(|r| r.label), Some(1));
+        assert_eq!(moore.region_at((1, 0)), None);
+    }
+}
@@ -0,0 +1,293 @@
+//! Searches on grids: breadth-first distance maps, Dijkstra and A*.
+//!
+//! Moves between cells go through [`Grid::get_coords`], so searches never leave the grid.
+//! [`dijkstra`] and [`astar`] search any state space, e.g. a cell together with the [`Direction`]
+//! it is entered from, which allows costs that depend on the move and not only on the cell.
+
+use std::cmp::Reverse;
+use std::collections::hash_map::Entry;
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+use std::hash::Hash;
+
+use crate::util::grid::{Direction, Grid};
+
+pub type Coords = (usize, usize);
+
+/// Distances from the nearest source to every cell of a grid, see [`bfs`].
+#[derive(Clone, Debug)]
+pub struct DistanceMap {
+    /// Number of moves from the nearest source, [`None`] for unreachable cells.
+    pub distances: Grid<Option<usize>>,
+    /// The cell each reachable cell was entered from, [`None`] for sources.
+    previous: Grid<Option<Coords>>,
+}
+
+impl DistanceMap {
+    pub fn distance(&self, coords: Coords) -> Option<usize> {
+        self.distances[coords]
+    }
+
+    /// A shortest path from the nearest source to `coords`, both included.
+    pub fn path_to(&self, coords: Coords) -> Option<Vec<Coords>> {
+        self.distance(coords)?;
+
+        let mut path = vec![coords];
+        while let Some(previous) = self.previous[*path.last().unwrap()] {
+            path.push(previous);
+        }
+        path.reverse();
+
+        Some(path)
+    }
+}
+
+/// Breadth-first search from all `sources` at once, moving in `directions` to cells that are open.
+pub fn bfs<T>(
+    grid: &Grid<T>,
+    sources: impl IntoIterator<Item = Coords>,
+    directions: &[Direction],
+    is_open: impl Fn(&T) -> bool,
+) -> DistanceMap {
+    let mut distances = Grid::filled(grid.width, grid.height, None);
+    let mut previous = Grid::filled(grid.width, grid.height, None);
+    let mut queue = VecDeque::new();
+
+    for source in sources {
+        if distances[source].is_none() {
+            distances[source] = Some(0);
+            queue.push_back(source);
+        }
+    }
+
+    while let Some(coords) = queue.pop_front() {
+        let distance = distances[coords].unwrap();
+
+        for direction in directions {
+            let Some(next) = grid.get_coords(*direction, coords.0, coords.1) else {
+                continue;
+            };
+            if distances[next].is_some() || !is_open(&grid[next]) {
+                continue;
+            }
+
+            distances[next] = Some(distance + 1);
+            previous[next] = Some(coords);
+            queue.push_back(next);
+        }
+    }
+
+    DistanceMap {
+        distances,
+        previous,
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// A cheapest path through a state space.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Path<S> {
+    pub cost: u64,
+    /// All states of the path, from the start to the goal.
+    pub states: Vec<S>,
+}
+
+/// Dijkstra's algorithm from all `starts` to the cheapest state that satisfies `is_goal`.
+///
+/// `successors` returns the states that can be reached from a state, each with the cost of the move.
+pub fn dijkstra<S, I>(
+    starts: impl IntoIterator<Item = S>,
+    successors: impl FnMut(&S) -> I,
+    is_goal: impl FnMut(&S) -> bool,
+) -> Option<Path<S>>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = (S, u64)>,
+{
+    astar(starts, successors, |_| 0, is_goal)
+}
+
+/// A* search, like [`dijkstra`] but guided by a `heuristic` that estimates the remaining cost of a
+/// state. The path is only guaranteed to be the cheapest if the heuristic never overestimates.
+pub fn astar<S, I>(
+    starts: impl IntoIterator<Item = S>,
+    mut successors: impl FnMut(&S) -> I,
+    mut heuristic: impl FnMut(&S) -> u64,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Option<Path<S>>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = (S, u64)>,
+{
+    // NOTE: states are numbered in order of discovery, so that the heap does not need `S: Ord`.
+    let mut ids: HashMap<S, usize> = HashMap::new();
+    let mut states: Vec<(S, u64, Option<usize>)> = vec![];
+    let mut heap = BinaryHeap::new();
+
+    for start in starts {
+        if let Entry::Vacant(entry) = ids.entry(start.clone()) {
+            entry.insert(states.len());
+            heap.push(Reverse((heuristic(&start), 0, states.len())));
+            states.push((start, 0, None));
+        }
+    }
+
+    while let Some(Reverse((_, cost, id))) = heap.pop() {
+        if cost > states[id].1 {
+            continue;
+        }
+
+        if is_goal(&states[id].0) {
+            let mut path = vec![];
+            let mut current = Some(id);
+            while let Some(id) = current {
+                path.push(states[id].0.clone());
+                current = states[id].2;
+            }
+            path.reverse();
+
+            return Some(Path { cost, states: path });
+        }
+
+        for (next, step) in successors(&states[id].0) {
+            let next_cost = cost + step;
+
+            let next_id = match ids.entry(next) {
+                Entry::Occupied(entry) => {
+                    let next_id = *entry.get();
+                    if next_cost >= states[next_id].1 {
+                        continue;
+                    }
+                    states[next_id].1 = next_cost;
+                    states[next_id].2 = Some(id);
+                    next_id
+                }
+                Entry::Vacant(entry) => {
+                    let next_id = states.len();
+                    states.push((entry.key().clone(), next_cost, Some(id)));
+                    entry.insert(next_id);
+                    next_id
+                }
+            };
+
+            let estimate = next_cost + heuristic(&states[next_id].0);
+            heap.push(Reverse((estimate, next_cost, next_id)));
+        }
+    }
+
+    None
+}
+
+/// Number of orthogonal moves between two cells.
+pub fn manhattan((x1, y1): Coords, (x2, y2): Coords) -> u64 {
+    (x1.abs_diff(x2) + y1.abs_diff(y2)) as u64
+}
+
+/// Cheapest orthogonal path between two cells with A*, where entering a cell costs `cost`.
+/// Cells without a cost are walls. Costs of at least 1 keep the Manhattan heuristic admissible.
+pub fn shortest_path<T>(
+    grid: &Grid<T>,
+    start: Coords,
+    goal: Coords,
+    cost: impl Fn(&T) -> Option<u64>,
+) -> Option<Path<Coords>> {
+    astar(
+        [start],
+        |coords| {
+            Direction::ORTHOGONAL
+                .into_iter()
+                .filter_map(|direction| grid.get_coords(direction, coords.0, coords.1))
+                .filter_map(|next| Some((next, cost(&grid[next])?)))
+                .collect::<Vec<_>>()
+        },
+        |coords| manhattan(*coords, goal),
+        |coords| *coords == goal,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MAZE: &str = "S.#.\n..#.\n....\n#..E";
+
+    #[test]
+    fn test_bfs_should_measure_distance_from_nearest_source() {
+        // GIVEN
+        let grid = Grid::parse_input(MAZE);
+
+        // WHEN
+        let map = bfs(&grid, [(0, 0), (3, 0)], &Direction::ORTHOGONAL, |c| {
+            *c != b'#'
+        });
+
+        // THEN
+        assert_eq!(map.distance((3, 3)), Some(3));
+        assert_eq!(map.distance((1, 2)), Some(3));
+        assert_eq!(map.distance((2, 0)), None);
+        assert_eq!(map.path_to((0, 2)), Some(vec![(0, 0), (0, 1), (0, 2)]));
+        assert_eq!(map.path_to((3, 0)), Some(vec![(3, 0)]));
+    }
+
+    #[test]
+    fn test_dijkstra_should_support_turn_penalties() {
+        // GIVEN
+        let grid = Grid::parse_input("S...\n.##.\n...E");
+        let start = grid.find(b'S').next().unwrap();
+        let goal = grid.find(b'E').next().unwrap();
+
+        // WHEN
+        let path = dijkstra(
+            [(start, Direction::South)],
+            |&(coords, direction)| {
+                let mut moves = vec![
+                    ((coords, direction.rotate_left()), 1000),
+                    ((coords, direction.rotate_right()), 1000),
+                ];
+                if let Some(next) = grid.get_coords(direction, coords.0, coords.1)
+                    && grid[next] != b'#'
+                {
+                    moves.push(((next, direction), 1));
+                }
+                moves
+            },
+            |(coords, _)| *coords == goal,
+        )
+        .unwrap();
+
+        // THEN
+        assert_eq!(path.cost, 1005);
+        assert_eq!(path.states.first(), Some(&(start, Direction::South)));
+        assert_eq!(path.states.last(), Some(&(goal, Direction::East)));
+    }
+
+    #[test]
+    fn test_shortest_path_should_avoid_expensive_cells() {
+        // GIVEN
+        let grid = Grid::parse_with("1911\n1911\n1111", |b| {
+            char::from(b).to_digit(10).ok_or("not a digit")
+        })
+        .unwrap();
+
+        // WHEN
+        let path = shortest_path(&grid, (0, 0), (3, 0), |c| Some(u64::from(*c))).unwrap();
+
+        // THEN
+        assert_eq!(path.cost, 7);
+        assert_eq!(path.states.len(), 8);
+        assert_eq!(path.states.first(), Some(&(0, 0)));
+        assert_eq!(path.states.last(), Some(&(3, 0)));
+    }
+
+    #[test]
+    fn test_shortest_path_should_return_none_when_unreachable() {
+        // GIVEN
+        let grid = Grid::parse_input(&MAZE.replace("..#.\n", "###.\n"));
+
+        // WHEN
+        let path = shortest_path(&grid, (0, 0), (3, 3), |c| (*c != b'#').then_some(1));
+
+        // THEN
+        assert_eq!(path, None);
+    }
+}